@@ -1,6 +1,9 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use chrono::{TimeZone, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -8,22 +11,24 @@ use std::process::Command as StdCommand;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime};
 use sysinfo::{System, Pid};
-use tauri::{State, Manager};
+use tauri::{Emitter, Manager, State};
 use std::thread;
+use tokio_util::sync::CancellationToken;
+use async_trait::async_trait;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct ServiceStatus {
-    name: String,
-    status: String, // "running", "stopped", "error", "unknown", "starting", "stopping"
-    pid: Option<u32>,
-    port: Option<u16>,
-    uptime: Option<u64>,
-    health: String, // "healthy", "unhealthy", "unknown"
-    cpu_usage: f32,
-    memory_usage: u64, // in MB
-    auto_restart_enabled: bool,
-    restart_count: u32,
+pub(crate) struct ServiceStatus {
+    pub(crate) name: String,
+    pub(crate) status: String, // "running", "stopped", "error", "unknown", "starting", "stopping"
+    pub(crate) pid: Option<u32>,
+    pub(crate) port: Option<u16>,
+    pub(crate) uptime: Option<u64>,
+    pub(crate) health: String, // "healthy", "unhealthy", "unknown"
+    pub(crate) cpu_usage: f32,
+    pub(crate) memory_usage: u64, // in MB
+    pub(crate) auto_restart_enabled: bool,
+    pub(crate) restart_count: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,13 +59,198 @@ struct LogEntry {
     source: String,
 }
 
+/// Pluggable distributed-coordination backend consulted by `restart_service`
+/// before it acts, so that multiple supervisor instances watching the same
+/// services don't double-bounce one of them. `LocalCoordinator` is the
+/// default for single-instance deployments; `ZkLockCoordinator` backs it with
+/// a real distributed lock for multi-instance ones.
+#[async_trait]
+trait Coordinator: Send + Sync {
+    /// Attempts to take ownership of `service_name` for the duration of one
+    /// restart. Returns `true` if this instance now holds the lock/lease and
+    /// should proceed; `false` if another instance already owns it.
+    async fn try_acquire(&self, service_name: &str) -> bool;
+
+    /// Releases a lock/lease previously granted by `try_acquire`. A no-op
+    /// for coordinators whose acquisition doesn't hold a lease.
+    async fn release(&self, service_name: &str);
+}
+
+/// No-op coordinator for single-instance deployments: every acquire succeeds
+/// immediately, so `restart_service` behaves exactly as it did before this
+/// backend existed.
+struct LocalCoordinator;
+
+#[async_trait]
+impl Coordinator for LocalCoordinator {
+    async fn try_acquire(&self, _service_name: &str) -> bool {
+        true
+    }
+
+    async fn release(&self, _service_name: &str) {}
+}
+
+/// Env var naming a ZooKeeper connect string (e.g. `zk1:2181,zk2:2181`).
+/// Unset by default, which keeps `AppState::new` on the single-instance
+/// `LocalCoordinator`.
+const ZK_COORDINATOR_CONNECT_ENV_VAR: &str = "DESKTOP_COMMANDER_ZK_CONNECT";
+/// Base znode path under which per-service lock directories are created.
+const ZK_COORDINATOR_BASE_PATH_ENV_VAR: &str = "DESKTOP_COMMANDER_ZK_BASE_PATH";
+const ZK_COORDINATOR_DEFAULT_BASE_PATH: &str = "/desktop-commander/locks";
+const ZK_SESSION_TIMEOUT_SECS: u64 = 10;
+
+const ZK_LOCK_POLL_INTERVAL_MS: u64 = 200;
+/// Upper bound on how long `ZkLockCoordinator::try_acquire`'s blocking poll
+/// loop may run. `spawn_blocking` tasks aren't cancelled when the awaiting
+/// future is dropped (e.g. `restart_service`'s `tokio::select!` picking the
+/// shutdown branch), so without this the thread could otherwise poll ZK on
+/// the blocking pool forever.
+const ZK_ACQUIRE_TIMEOUT_SECS: u64 = 30;
+
+/// No-op `Watcher`: this coordinator polls for lock ownership itself rather
+/// than reacting to ZK session/watch events.
+struct NoopWatcher;
+
+impl zookeeper::Watcher for NoopWatcher {
+    fn handle(&self, _event: zookeeper::WatchedEvent) {}
+}
+
+/// ZooKeeper ephemeral-sequential-znode lock recipe: creates a uniquely
+/// named ephemeral child under `<base_path>/<service_name>/`, and only
+/// considers the lock held once this instance's node is the lowest-numbered
+/// child. Ownership is checked by re-listing the whole lock directory every
+/// `ZK_LOCK_POLL_INTERVAL_MS`; no watch is set on the predecessor node.
+/// Because the node is ephemeral, a crashed or partitioned
+/// supervisor instance loses the lock automatically when its ZK session
+/// expires, instead of leaving the service permanently un-restartable.
+struct ZkLockCoordinator {
+    client: zookeeper::ZooKeeper,
+    base_path: String,
+    held_nodes: Mutex<HashMap<String, String>>,
+}
+
+impl ZkLockCoordinator {
+    fn new(client: zookeeper::ZooKeeper, base_path: String) -> Self {
+        Self {
+            client,
+            base_path,
+            held_nodes: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl Coordinator for ZkLockCoordinator {
+    async fn try_acquire(&self, service_name: &str) -> bool {
+        let client = self.client.clone();
+        let lock_dir = format!("{}/{}", self.base_path, service_name);
+
+        let acquired = tokio::task::spawn_blocking(move || -> zookeeper::ZkResult<String> {
+            client.ensure_path(&lock_dir)?;
+
+            let my_path = client.create(
+                &format!("{}/lock-", lock_dir),
+                vec![],
+                zookeeper::Acl::open_unsafe().clone(),
+                zookeeper::CreateMode::EphemeralSequential,
+            )?;
+            let my_node = my_path.rsplit('/').next().unwrap_or(&my_path).to_string();
+            let deadline = std::time::Instant::now() + Duration::from_secs(ZK_ACQUIRE_TIMEOUT_SECS);
+
+            loop {
+                let mut children = client.get_children(&lock_dir, false)?;
+                children.sort();
+
+                if children.first().map(String::as_str) == Some(my_node.as_str()) {
+                    return Ok(my_path);
+                }
+
+                if std::time::Instant::now() >= deadline {
+                    // Give up our place in line instead of leaving an
+                    // ephemeral node around (and this thread polling
+                    // forever) for a caller that may no longer be waiting.
+                    let _ = client.delete(&my_path, None);
+                    return Err(zookeeper::ZkError::OperationTimeout);
+                }
+
+                // Only the node immediately ahead of us in sequence order can
+                // unblock us. It is looked up without a watch (`false`); the
+                // next pass of this loop re-lists the whole directory.
+                let my_index = children.iter().position(|c| c == &my_node).unwrap_or(0);
+                if my_index > 0 {
+                    let predecessor = format!("{}/{}", lock_dir, children[my_index - 1]);
+                    let _ = client.exists(&predecessor, false);
+                }
+
+                thread::sleep(Duration::from_millis(ZK_LOCK_POLL_INTERVAL_MS));
+            }
+        })
+        .await;
+
+        match acquired {
+            Ok(Ok(path)) => {
+                self.held_nodes.lock().unwrap().insert(service_name.to_string(), path);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    async fn release(&self, service_name: &str) {
+        let path = self.held_nodes.lock().unwrap().remove(service_name);
+        if let Some(path) = path {
+            let client = self.client.clone();
+            let _ = tokio::task::spawn_blocking(move || client.delete(&path, None)).await;
+        }
+    }
+}
+
+/// Picks the `Coordinator` backend for this process: a `ZkLockCoordinator`
+/// when `DESKTOP_COMMANDER_ZK_CONNECT` is set, falling back to the
+/// single-instance `LocalCoordinator` if it's unset or the connect attempt
+/// fails (so a misconfigured/unreachable ZK ensemble doesn't stop the app
+/// from starting, only disables cross-instance restart coordination).
+fn build_coordinator() -> Arc<dyn Coordinator> {
+    let Ok(connect_string) = std::env::var(ZK_COORDINATOR_CONNECT_ENV_VAR) else {
+        return Arc::new(LocalCoordinator);
+    };
+    let base_path = std::env::var(ZK_COORDINATOR_BASE_PATH_ENV_VAR)
+        .unwrap_or_else(|_| ZK_COORDINATOR_DEFAULT_BASE_PATH.to_string());
+
+    match zookeeper::ZooKeeper::connect(
+        &connect_string,
+        Duration::from_secs(ZK_SESSION_TIMEOUT_SECS),
+        NoopWatcher,
+    ) {
+        Ok(client) => Arc::new(ZkLockCoordinator::new(client, base_path)),
+        Err(e) => {
+            eprintln!("failed to connect to ZooKeeper at {}: {} (falling back to LocalCoordinator)", connect_string, e);
+            Arc::new(LocalCoordinator)
+        }
+    }
+}
+
 struct AppState {
     monorepo_path: PathBuf,
     services: Mutex<HashMap<String, ServiceConfig>>,
     process_tracker: Arc<Mutex<ProcessTracker>>,
+    config_path: PathBuf,
+    /// Cancelled once `shutdown()` runs; `restart_service` selects on it so a
+    /// shutdown in progress aborts in-flight restarts instead of waiting for
+    /// each one to finish.
+    shutdown_token: CancellationToken,
+    /// Handles for restarts spawned by `monitor_auto_restart`, awaited (with
+    /// a bounded timeout) by `shutdown()` so the process doesn't exit out
+    /// from under a restart that's still stopping/starting a child process.
+    restart_tasks: Mutex<Vec<tokio::task::JoinHandle<()>>>,
+    /// Selected by `build_coordinator`: a `ZkLockCoordinator` when
+    /// `DESKTOP_COMMANDER_ZK_CONNECT` is set (for multiple supervisor
+    /// instances against shared services), otherwise the no-op
+    /// `LocalCoordinator`.
+    coordinator: Arc<dyn Coordinator>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct ServiceConfig {
     name: String,
     command: String,
@@ -69,13 +259,130 @@ struct ServiceConfig {
     health_check_url: Option<String>,
     dependencies: Vec<String>,
     auto_restart: bool,
+    max_restarts: u32,
+    #[serde(default)]
+    command_args: Vec<String>,
+    log_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ServicesFile {
+    monorepo_path: PathBuf,
+    services: Vec<ServiceConfig>,
 }
 
 #[derive(Debug)]
 struct ProcessTracker {
     processes: HashMap<String, ProcessInfo>,
+    circuit_breakers: HashMap<String, CircuitBreaker>,
+    restart_policies: HashMap<String, RestartPolicy>,
+}
+
+// Circuit breaker envelope: trip after this many failures within the window,
+// try one trial restart after the cooldown elapses.
+const CIRCUIT_FAILURE_THRESHOLD: u32 = 3;
+const CIRCUIT_WINDOW_SECS: u64 = 300;
+const CIRCUIT_OPEN_COOLDOWN_SECS: u64 = 120;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
 }
 
+/// Per-service circuit breaker: trips `Closed` -> `Open` after
+/// `CIRCUIT_FAILURE_THRESHOLD` failures inside `CIRCUIT_WINDOW_SECS`, refuses
+/// restarts while `Open`, then allows a single `HalfOpen` trial restart once
+/// `CIRCUIT_OPEN_COOLDOWN_SECS` has elapsed, returning to `Closed` on success.
+#[derive(Debug, Clone)]
+struct CircuitBreaker {
+    state: CircuitState,
+    consecutive_failures: u32,
+    window_start: Option<SystemTime>,
+    opened_at: Option<SystemTime>,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            window_start: None,
+            opened_at: None,
+        }
+    }
+}
+
+impl CircuitBreaker {
+    fn record_failure(&mut self) {
+        let now = SystemTime::now();
+        let window_expired = self
+            .window_start
+            .map(|start| start.elapsed().unwrap_or_default().as_secs() > CIRCUIT_WINDOW_SECS)
+            .unwrap_or(true);
+        if window_expired {
+            self.window_start = Some(now);
+            self.consecutive_failures = 0;
+        }
+        self.consecutive_failures += 1;
+
+        // Failures while already `Open` leave `opened_at` alone, so the
+        // cooldown can elapse even while the service stays down.
+        let trips = match self.state {
+            CircuitState::Closed => self.consecutive_failures >= CIRCUIT_FAILURE_THRESHOLD,
+            CircuitState::HalfOpen => true,
+            CircuitState::Open => false,
+        };
+        if trips {
+            self.state = CircuitState::Open;
+            self.opened_at = Some(now);
+        }
+    }
+
+    /// A healthy observation closes the breaker: it confirms the `HalfOpen`
+    /// trial restart worked, or simply keeps a `Closed` breaker clean.
+    fn record_success(&mut self) {
+        if self.state != CircuitState::Open {
+            self.state = CircuitState::Closed;
+            self.consecutive_failures = 0;
+            self.window_start = None;
+            self.opened_at = None;
+        }
+    }
+
+    /// Promotes `Open` to `HalfOpen` once the cooldown has elapsed so the
+    /// next restart attempt is treated as a single trial.
+    fn tick(&mut self) {
+        if self.state == CircuitState::Open {
+            if let Some(opened_at) = self.opened_at {
+                if opened_at.elapsed().unwrap_or_default().as_secs() > CIRCUIT_OPEN_COOLDOWN_SECS {
+                    self.state = CircuitState::HalfOpen;
+                }
+            }
+        }
+    }
+
+    fn allows_restart(&self) -> bool {
+        self.state != CircuitState::Open
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self.state {
+            CircuitState::Closed => "CLOSED",
+            CircuitState::Open => "OPEN",
+            CircuitState::HalfOpen => "HALF_OPEN",
+        }
+    }
+}
+
+// Exponential backoff envelope for auto-restart.
+const RESTART_BACKOFF_BASE_SECS: u64 = 30;
+const RESTART_BACKOFF_MAX_SECS: u64 = 300;
+const RESTART_WINDOW_SECS: u64 = 600;
+const RESTART_COOLDOWN_SECS: u64 = 120;
+
 #[derive(Debug, Clone)]
 struct ProcessInfo {
     pid: u32,
@@ -83,18 +390,130 @@ struct ProcessInfo {
     restart_count: u32,
     last_health_check: Option<SystemTime>,
     health_status: String,
+    /// Why the previously tracked process went away, e.g. `"manual_stop"` or
+    /// `"auto_restart"`. Carried over across restarts so it reflects the most
+    /// recent exit rather than being cleared the moment a new one starts.
+    last_exit_reason: Option<String>,
 }
 
-impl AppState {
-    fn new() -> Self {
-        // Your monorepo root
-        let monorepo_path = PathBuf::from("C:\\dev");
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RestartPolicyState {
+    Healthy,
+    Backoff,
+    Failed,
+}
+
+/// Per-service supervision policy: records attempt count and last-restart
+/// timestamp, computes exponential backoff between attempts, and trips to
+/// `Failed` (quarantined, no further auto-restarts) once `max_attempts` are
+/// exceeded within `RESTART_WINDOW_SECS`. A service that stays up for
+/// `RESTART_COOLDOWN_SECS` earns a clean slate back to `Healthy`.
+#[derive(Debug, Clone)]
+struct RestartPolicy {
+    state: RestartPolicyState,
+    attempts: u32,
+    last_attempt: Option<SystemTime>,
+    window_start: Option<SystemTime>,
+    /// Set while a restart has been scheduled on the Tokio timer but hasn't
+    /// fired yet, so the supervisor loop doesn't double-schedule it.
+    scheduled: bool,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            state: RestartPolicyState::Healthy,
+            attempts: 0,
+            last_attempt: None,
+            window_start: None,
+            scheduled: false,
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// `base * 2^attempts` capped at `RESTART_BACKOFF_MAX_SECS`, multiplied
+    /// by a `[0.5, 1.0]` jitter factor so crash-looping services across the
+    /// monorepo don't all retry in lockstep.
+    fn next_backoff(&self) -> Duration {
+        let exp = RESTART_BACKOFF_BASE_SECS.saturating_mul(1u64 << self.attempts.min(16));
+        let capped = exp.min(RESTART_BACKOFF_MAX_SECS);
+        let jitter = 0.5 + rand::random::<f64>() * 0.5;
+        Duration::from_secs_f64(capped as f64 * jitter)
+    }
+
+    /// How much longer to wait before the next restart attempt is due.
+    /// `Duration::ZERO` if a restart can fire right away.
+    fn remaining_backoff(&self) -> Duration {
+        match self.last_attempt {
+            Some(last) => self.next_backoff().saturating_sub(last.elapsed().unwrap_or_default()),
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Records a restart attempt, resetting the rolling window if it has
+    /// expired, and trips to `Failed` once `max_attempts` is exceeded within
+    /// the window.
+    fn record_attempt(&mut self, max_attempts: u32) {
+        let now = SystemTime::now();
+
+        let window_expired = self
+            .window_start
+            .map(|start| start.elapsed().unwrap_or_default().as_secs() > RESTART_WINDOW_SECS)
+            .unwrap_or(true);
+        if window_expired {
+            self.window_start = Some(now);
+            self.attempts = 0;
+        }
+
+        self.attempts += 1;
+        self.last_attempt = Some(now);
+        self.scheduled = false;
+        self.state = if self.attempts > max_attempts {
+            RestartPolicyState::Failed
+        } else {
+            RestartPolicyState::Backoff
+        };
+    }
+
+    /// Clears the failure counter once a service has stayed healthy for
+    /// `RESTART_COOLDOWN_SECS`, letting it earn a clean slate.
+    fn record_success(&mut self) {
+        if self.attempts > 0 {
+            if let Some(last) = self.last_attempt {
+                if last.elapsed().unwrap_or_default().as_secs() > RESTART_COOLDOWN_SECS {
+                    self.attempts = 0;
+                    self.window_start = None;
+                    self.state = RestartPolicyState::Healthy;
+                }
+            }
+        }
+    }
+
+    fn is_failed(&self) -> bool {
+        self.state == RestartPolicyState::Failed
+    }
+}
+
+impl RestartPolicyState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RestartPolicyState::Healthy => "HEALTHY",
+            RestartPolicyState::Backoff => "BACKOFF",
+            RestartPolicyState::Failed => "FAILED",
+        }
+    }
+}
 
-        let mut services = HashMap::new();
+const SERVICES_CONFIG_FILENAME: &str = "services.json";
+
+impl ServicesFile {
+    /// The hardcoded set this app shipped with before service definitions
+    /// became configurable; written out as `services.json` on first launch.
+    fn default_for_author_machine() -> Self {
+        let monorepo_path = PathBuf::from("C:\\dev");
 
-        // Main Web App (Vite + React)
-        services.insert(
-            "web-app".to_string(),
+        let services = vec![
             ServiceConfig {
                 name: "web-app".to_string(),
                 command: "npm run dev".to_string(),
@@ -103,12 +522,10 @@ impl AppState {
                 health_check_url: Some("http://localhost:5173".to_string()),
                 dependencies: vec!["backend".to_string()],
                 auto_restart: false,
+                max_restarts: 5,
+                command_args: vec![],
+                log_path: Some(monorepo_path.join("vite-web-app.log")),
             },
-        );
-
-        // Backend Server (Node.js + Express)
-        services.insert(
-            "backend".to_string(),
             ServiceConfig {
                 name: "backend".to_string(),
                 command: "npm run dev".to_string(),
@@ -116,27 +533,23 @@ impl AppState {
                 port: Some(3000),
                 health_check_url: Some("http://localhost:3000/health".to_string()),
                 dependencies: vec![],
-                auto_restart: false,  // Disabled to prevent startup issues
+                auto_restart: false, // Disabled to prevent startup issues
+                max_restarts: 5,
+                command_args: vec![],
+                log_path: Some(monorepo_path.join("backend").join("backend.log")),
             },
-        );
-
-        // Crypto Trading Bot (Python)
-        services.insert(
-            "trading-bot".to_string(),
             ServiceConfig {
                 name: "trading-bot".to_string(),
-                command: ".venv\\Scripts\\python.exe start_live_trading.py".to_string(),
+                command: ".venv\\Scripts\\python.exe".to_string(),
                 working_dir: monorepo_path.join("projects\\crypto-enhanced"),
                 port: None,
                 health_check_url: None,
                 dependencies: vec![],
-                auto_restart: false,  // Disabled to prevent immediate startup issues
+                auto_restart: false, // Disabled to prevent immediate startup issues
+                max_restarts: 5,
+                command_args: vec!["start_live_trading.py".to_string()],
+                log_path: Some(monorepo_path.join("trading_new.log")),
             },
-        );
-
-        // Business Booking Platform
-        services.insert(
-            "booking-platform".to_string(),
             ServiceConfig {
                 name: "booking-platform".to_string(),
                 command: "npm run dev".to_string(),
@@ -145,12 +558,14 @@ impl AppState {
                 health_check_url: Some("http://localhost:5174".to_string()),
                 dependencies: vec![],
                 auto_restart: false,
+                max_restarts: 5,
+                command_args: vec![],
+                log_path: Some(
+                    monorepo_path
+                        .join("projects\\active\\web-apps\\business-booking-platform")
+                        .join("vite.log"),
+                ),
             },
-        );
-
-        // Digital Content Builder
-        services.insert(
-            "content-builder".to_string(),
             ServiceConfig {
                 name: "content-builder".to_string(),
                 command: "npm run dev".to_string(),
@@ -159,29 +574,112 @@ impl AppState {
                 health_check_url: Some("http://localhost:5175".to_string()),
                 dependencies: vec![],
                 auto_restart: false,
+                max_restarts: 5,
+                command_args: vec![],
+                log_path: Some(
+                    monorepo_path
+                        .join("projects\\active\\web-apps\\digital-content-builder")
+                        .join("vite.log"),
+                ),
             },
-        );
-        
+        ];
+
+        Self { monorepo_path, services }
+    }
+}
+
+/// Loads `services.json` from the app config directory, writing out the
+/// author's original hardcoded set as a first-launch default when it's
+/// missing so the app is usable out of the box for anyone else.
+fn load_services_file(config_path: &std::path::Path) -> Result<ServicesFile, String> {
+    if !config_path.exists() {
+        let default = ServicesFile::default_for_author_machine();
+        if let Some(parent) = config_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("failed to create config dir: {}", e))?;
+        }
+        let json = serde_json::to_string_pretty(&default).map_err(|e| e.to_string())?;
+        std::fs::write(config_path, json).map_err(|e| format!("failed to write default services config: {}", e))?;
+        return Ok(default);
+    }
+
+    let contents = std::fs::read_to_string(config_path)
+        .map_err(|e| format!("failed to read services config: {}", e))?;
+    serde_json::from_str(&contents).map_err(|e| format!("failed to parse services config: {}", e))
+}
+
+impl AppState {
+    fn new(services_file: ServicesFile, config_path: PathBuf) -> Self {
+        let services: HashMap<String, ServiceConfig> = services_file
+            .services
+            .into_iter()
+            .map(|cfg| (cfg.name.clone(), cfg))
+            .collect();
+
         Self {
-            monorepo_path,
+            monorepo_path: services_file.monorepo_path,
             services: Mutex::new(services),
             process_tracker: Arc::new(Mutex::new(ProcessTracker {
                 processes: HashMap::new(),
+                circuit_breakers: HashMap::new(),
+                restart_policies: HashMap::new(),
             })),
+            config_path,
+            shutdown_token: CancellationToken::new(),
+            restart_tasks: Mutex::new(Vec::new()),
+            coordinator: build_coordinator(),
+        }
+    }
+
+    /// Cancels the shutdown token so in-flight `restart_service` calls abort,
+    /// then waits up to `SHUTDOWN_TIMEOUT_SECS` for any restarts already
+    /// spawned by `monitor_auto_restart` to actually wind down.
+    async fn shutdown(&self) {
+        self.shutdown_token.cancel();
+
+        let tasks: Vec<_> = self.restart_tasks.lock().unwrap().drain(..).collect();
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(SHUTDOWN_TIMEOUT_SECS);
+
+        for task in tasks {
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if tokio::time::timeout(remaining, task).await.is_err() {
+                eprintln!("Supervisor shutdown: a restart task did not finish within the timeout");
+            }
         }
     }
 }
 
+const SHUTDOWN_TIMEOUT_SECS: u64 = 10;
+
+#[tauri::command]
+fn reload_config(state: State<AppState>) -> Result<Vec<String>, String> {
+    let services_file = load_services_file(&state.config_path)?;
+    let mut services = state.services.lock().unwrap();
+    *services = services_file
+        .services
+        .into_iter()
+        .map(|cfg| (cfg.name.clone(), cfg))
+        .collect();
+    Ok(services.keys().cloned().collect())
+}
+
 impl ProcessTracker {
+    /// Tracks a freshly-started process, carrying forward the cumulative
+    /// `restart_count` from a prior run of the same service rather than
+    /// resetting it on every start. Restart/backoff bookkeeping itself lives
+    /// in `restart_policies`, keyed separately so it survives independent of
+    /// this entry being replaced.
     fn track_process(&mut self, service_name: String, pid: u32) {
+        let carried_over = self.processes.get(&service_name).cloned();
+
         self.processes.insert(
             service_name,
             ProcessInfo {
                 pid,
                 start_time: SystemTime::now(),
-                restart_count: 0,
+                restart_count: carried_over.as_ref().map(|p| p.restart_count).unwrap_or(0),
                 last_health_check: None,
                 health_status: "unknown".to_string(),
+                last_exit_reason: carried_over.and_then(|p| p.last_exit_reason),
             },
         );
     }
@@ -190,13 +688,47 @@ impl ProcessTracker {
         self.processes.get(service_name)
     }
 
-    fn remove_process(&mut self, service_name: &str) {
-        self.processes.remove(service_name);
+    /// Records why the tracked process was last brought down, surfaced later
+    /// through `metrics_snapshot` for operators debugging a flapping service.
+    fn set_last_exit_reason(&mut self, service_name: &str, reason: &str) {
+        if let Some(info) = self.processes.get_mut(service_name) {
+            info.last_exit_reason = Some(reason.to_string());
+        }
+    }
+
+    /// Returns the current restart policy for `service_name`, or a fresh
+    /// `Healthy` one if this is the first time it's been observed.
+    fn restart_policy(&self, service_name: &str) -> RestartPolicy {
+        self.restart_policies.get(service_name).cloned().unwrap_or_default()
     }
 
-    fn increment_restart_count(&mut self, service_name: &str) {
+    /// Marks a restart as scheduled on the Tokio timer so the supervisor
+    /// loop doesn't schedule a second one for the same service while the
+    /// first is still waiting out its backoff.
+    fn mark_restart_scheduled(&mut self, service_name: &str) {
+        self.restart_policies.entry(service_name.to_string()).or_default().scheduled = true;
+    }
+
+    /// Records a restart attempt against the rolling-window restart policy,
+    /// tripping it to `Failed` once it exceeds `max_restarts` within
+    /// `RESTART_WINDOW_SECS`. Also bumps the cumulative restart counter used
+    /// for status/metrics reporting.
+    fn record_restart_attempt(&mut self, service_name: &str, max_restarts: u32) {
+        self.restart_policies.entry(service_name.to_string()).or_default().record_attempt(max_restarts);
+
         if let Some(info) = self.processes.get_mut(service_name) {
             info.restart_count += 1;
+            if self.restart_policies[service_name].is_failed() {
+                info.health_status = "unhealthy".to_string();
+            }
+        }
+    }
+
+    /// Clears the failure counter once a service has stayed healthy for
+    /// `RESTART_COOLDOWN_SECS`, letting it earn a clean slate.
+    fn maybe_reset_after_cooldown(&mut self, service_name: &str) {
+        if let Some(policy) = self.restart_policies.get_mut(service_name) {
+            policy.record_success();
         }
     }
 
@@ -206,6 +738,22 @@ impl ProcessTracker {
             info.last_health_check = Some(SystemTime::now());
         }
     }
+
+    fn record_circuit_failure(&mut self, service_name: &str) {
+        self.circuit_breakers.entry(service_name.to_string()).or_default().record_failure();
+    }
+
+    fn record_circuit_success(&mut self, service_name: &str) {
+        self.circuit_breakers.entry(service_name.to_string()).or_default().record_success();
+    }
+
+    /// Advances `Open` breakers past their cooldown to `HalfOpen` and returns
+    /// the resulting state for `service_name`.
+    fn circuit_state(&mut self, service_name: &str) -> CircuitBreaker {
+        let breaker = self.circuit_breakers.entry(service_name.to_string()).or_default();
+        breaker.tick();
+        breaker.clone()
+    }
 }
 
 #[tauri::command]
@@ -290,16 +838,19 @@ async fn check_service_health(service_name: String, state: State<'_, AppState>)
                 if response.status().is_success() {
                     let mut tracker = state.process_tracker.lock().unwrap();
                     tracker.update_health(&service_name, "healthy".to_string());
+                    tracker.record_circuit_success(&service_name);
                     Ok("healthy".to_string())
                 } else {
                     let mut tracker = state.process_tracker.lock().unwrap();
                     tracker.update_health(&service_name, "unhealthy".to_string());
+                    tracker.record_circuit_failure(&service_name);
                     Ok("unhealthy".to_string())
                 }
             }
             Err(_) => {
                 let mut tracker = state.process_tracker.lock().unwrap();
                 tracker.update_health(&service_name, "unhealthy".to_string());
+                tracker.record_circuit_failure(&service_name);
                 Ok("unhealthy".to_string())
             }
         }
@@ -310,11 +861,13 @@ async fn check_service_health(service_name: String, state: State<'_, AppState>)
                 Ok(_) => {
                     let mut tracker = state.process_tracker.lock().unwrap();
                     tracker.update_health(&service_name, "healthy".to_string());
+                    tracker.record_circuit_success(&service_name);
                     Ok("healthy".to_string())
                 }
                 Err(_) => {
                     let mut tracker = state.process_tracker.lock().unwrap();
                     tracker.update_health(&service_name, "unhealthy".to_string());
+                    tracker.record_circuit_failure(&service_name);
                     Ok("unhealthy".to_string())
                 }
             }
@@ -326,22 +879,159 @@ async fn check_service_health(service_name: String, state: State<'_, AppState>)
 
 #[tauri::command]
 fn get_all_services_status(state: State<AppState>) -> Result<Vec<ServiceStatus>, String> {
-    let services = state.services.lock().unwrap();
+    // Collect names and drop the lock before calling `get_service_status`,
+    // which takes this same `std::sync::Mutex` itself — holding it here too
+    // would self-deadlock on a non-reentrant mutex.
+    let service_names: Vec<String> = state.services.lock().unwrap().keys().cloned().collect();
     let mut statuses = Vec::new();
-    
-    for service_name in services.keys() {
+
+    for service_name in service_names {
         match get_service_status(service_name.clone(), state.clone()) {
             Ok(status) => statuses.push(status),
             Err(e) => eprintln!("Error getting status for {}: {}", service_name, e),
         }
     }
-    
+
     Ok(statuses)
 }
 
+/// Supervisor-level health for a single service: restart history, current
+/// backoff/circuit state, and the last known reason it went down. This is
+/// deliberately separate from `ServiceStatus` (which reports live
+/// CPU/memory/pid) so operators can scrape restart-rate trends without
+/// paying for a `sysinfo` refresh on every poll.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ServiceMetrics {
+    service_name: String,
+    restart_count: u32,
+    uptime_seconds: u64,
+    last_exit_reason: Option<String>,
+    backoff_state: String,
+    circuit_state: String,
+    health_status: String,
+}
+
+/// Process-wide Tokio executor stats from `Handle::metrics()`, surfaced so
+/// operators can tell "a service is flapping" apart from "the runtime itself
+/// is saturated".
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TokioRuntimeMetrics {
+    worker_threads: usize,
+    blocking_threads: usize,
+    alive_tasks: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MetricsSnapshot {
+    services: Vec<ServiceMetrics>,
+    runtime: TokioRuntimeMetrics,
+}
+
+fn collect_service_metrics(state: &State<AppState>) -> Vec<ServiceMetrics> {
+    let services = state.services.lock().unwrap();
+    let mut tracker = state.process_tracker.lock().unwrap();
+
+    services
+        .keys()
+        .map(|name| {
+            let info = tracker.get_process_info(name).cloned();
+            let policy = tracker.restart_policy(name);
+            let circuit = tracker.circuit_state(name);
+
+            ServiceMetrics {
+                service_name: name.clone(),
+                restart_count: info.as_ref().map(|i| i.restart_count).unwrap_or(0),
+                uptime_seconds: info
+                    .as_ref()
+                    .map(|i| i.start_time.elapsed().unwrap_or_default().as_secs())
+                    .unwrap_or(0),
+                last_exit_reason: info.and_then(|i| i.last_exit_reason),
+                backoff_state: policy.state.as_str().to_string(),
+                circuit_state: circuit.as_str().to_string(),
+                health_status: info
+                    .map(|i| i.health_status)
+                    .unwrap_or_else(|| "unknown".to_string()),
+            }
+        })
+        .collect()
+}
+
+fn collect_runtime_metrics() -> TokioRuntimeMetrics {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => {
+            let metrics = handle.metrics();
+            TokioRuntimeMetrics {
+                worker_threads: metrics.num_workers(),
+                blocking_threads: metrics.num_blocking_threads(),
+                alive_tasks: metrics.num_alive_tasks(),
+            }
+        }
+        Err(_) => TokioRuntimeMetrics {
+            worker_threads: 0,
+            blocking_threads: 0,
+            alive_tasks: 0,
+        },
+    }
+}
+
+/// One-shot snapshot of restart/backoff/circuit state for every configured
+/// service plus process-wide Tokio runtime stats, so operators can scrape
+/// restart-rate spikes instead of grepping logs.
+#[tauri::command]
+fn metrics_snapshot(state: State<AppState>) -> Result<MetricsSnapshot, String> {
+    Ok(MetricsSnapshot {
+        services: collect_service_metrics(&state),
+        runtime: collect_runtime_metrics(),
+    })
+}
+
+/// Payload for the `service-progress` event emitted as long-running commands
+/// (starting the whole stack, restarting a service, replaying a workload)
+/// move through their begin/starting-dependency/waiting-for-health/done phases.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ServiceProgress {
+    current: u32,
+    total: u32,
+    service_name: String,
+    phase: String,
+}
+
+fn emit_progress(app: &tauri::AppHandle, current: u32, total: u32, service_name: &str, phase: &str) {
+    let payload = ServiceProgress {
+        current,
+        total,
+        service_name: service_name.to_string(),
+        phase: phase.to_string(),
+    };
+    if let Err(e) = app.emit("service-progress", &payload) {
+        eprintln!("Failed to emit service-progress event: {}", e);
+    }
+}
+
 #[tauri::command]
-async fn start_service(service_name: String, state: State<'_, AppState>) -> Result<bool, String> {
-    let (dependencies, working_dir, command, port, name) = {
+async fn start_service(app: tauri::AppHandle, service_name: String, state: State<'_, AppState>) -> Result<bool, String> {
+    start_service_inner(&app, service_name, 1, 1, state).await
+}
+
+/// Shared implementation behind the `start_service` command and every other
+/// call site (dependency recursion, `start_all_services`, `restart_service`,
+/// `run_workload`) so they all emit the same `service-progress` phases.
+/// `current`/`total` describe this service's position within whatever
+/// higher-level operation is driving the start, or `(1, 1)` for a standalone start.
+async fn start_service_inner(
+    app: &tauri::AppHandle,
+    service_name: String,
+    current: u32,
+    total: u32,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    emit_progress(app, current, total, &service_name, "begin");
+
+    let (dependencies, working_dir, command, command_args, port, name) = {
         let services = state.services.lock().unwrap();
         let service = services.get(&service_name)
             .ok_or_else(|| format!("Service {} not found", service_name))?;
@@ -349,6 +1039,7 @@ async fn start_service(service_name: String, state: State<'_, AppState>) -> Resu
             service.dependencies.clone(),
             service.working_dir.clone(),
             service.command.clone(),
+            service.command_args.clone(),
             service.port,
             service.name.clone(),
         )
@@ -359,33 +1050,39 @@ async fn start_service(service_name: String, state: State<'_, AppState>) -> Resu
         let dep_status = get_service_status(dep.clone(), state.clone())?;
         if dep_status.status != "running" {
             println!("Starting dependency: {}", dep);
+            emit_progress(app, current, total, dep, "starting_dependency");
             // Use Box::pin for recursive async call
-            Box::pin(start_service(dep.clone(), state.clone())).await?;
+            Box::pin(start_service_inner(app, dep.clone(), current, total, state.clone())).await?;
             // Wait a bit for dependency to start
             tokio::time::sleep(Duration::from_secs(2)).await;
         }
     }
 
     // Use PowerShell to start the service in a new window
+    let full_command = if command_args.is_empty() {
+        command
+    } else {
+        format!("{} {}", command, command_args.join(" "))
+    };
     let ps_command = format!(
         "Start-Process powershell -ArgumentList '-NoExit', '-Command', 'cd \"{}\"; {}'",
         working_dir.display(),
-        command
+        full_command
     );
 
     let output = StdCommand::new("powershell")
         .args(&["-Command", &ps_command])
         .output()
         .map_err(|e| format!("Failed to start service: {}", e))?;
-    
+
     if output.status.success() {
         // Track the process (we'll need to find PID after a moment)
         tokio::time::sleep(Duration::from_millis(500)).await;
-        
+
         // Try to find the PID
         let mut sys = System::new_all();
         sys.refresh_all();
-        
+
         for (pid, process) in sys.processes() {
             let cmd = process.cmd().join(" ");
             if cmd.contains(&name) ||
@@ -395,9 +1092,11 @@ async fn start_service(service_name: String, state: State<'_, AppState>) -> Resu
                 break;
             }
         }
-        
+
+        emit_progress(app, current, total, &name, "done");
         Ok(true)
     } else {
+        emit_progress(app, current, total, &name, "done");
         Ok(false)
     }
 }
@@ -407,13 +1106,13 @@ fn stop_service(service_name: String, state: State<AppState>) -> Result<bool, St
     let services = state.services.lock().unwrap();
     let service = services.get(&service_name)
         .ok_or_else(|| format!("Service {} not found", service_name))?;
-    
-    // Remove from tracker
-    {
-        let mut tracker = state.process_tracker.lock().unwrap();
-        tracker.remove_process(&service_name);
-    }
-    
+
+    // Note: the tracker entry is left in place (rather than removed) so that
+    // restart bookkeeping (restart_count, backoff state) survives a stop.
+    // `get_service_status` already reports "stopped" once the tracked pid is
+    // no longer found in the process table.
+    state.process_tracker.lock().unwrap().set_last_exit_reason(&service_name, "manual_stop");
+
     // Kill process by port if available
     if let Some(port) = service.port {
         let ps_command = format!(
@@ -433,67 +1132,192 @@ fn stop_service(service_name: String, state: State<AppState>) -> Result<bool, St
 }
 
 #[tauri::command]
-async fn restart_service(service_name: String, state: State<'_, AppState>) -> Result<bool, String> {
+async fn restart_service(app: tauri::AppHandle, service_name: String, state: State<'_, AppState>) -> Result<bool, String> {
+    let token = state.shutdown_token.clone();
+    if token.is_cancelled() {
+        return Err(format!("supervisor is shutting down; refusing to restart {}", service_name));
+    }
+
+    tokio::select! {
+        result = restart_service_inner(&app, service_name.clone(), state) => result,
+        _ = token.cancelled() => {
+            println!("Restart of {} cancelled: supervisor is shutting down", service_name);
+            Ok(false)
+        }
+    }
+}
+
+/// RAII guard around a coordinator lock held for the duration of one
+/// restart. If the future holding this guard is dropped before calling
+/// `release` on the normal path — e.g. `restart_service`'s `tokio::select!`
+/// picking the shutdown branch after `try_acquire` already succeeded — the
+/// lock would otherwise leak forever, since `Coordinator::release` is async
+/// and can't run from a synchronous `Drop`. Instead, `Drop` spawns a
+/// detached task to release it.
+struct CoordinatorLockGuard {
+    coordinator: Arc<dyn Coordinator>,
+    service_name: String,
+    released: bool,
+}
+
+impl CoordinatorLockGuard {
+    async fn release(mut self) {
+        self.coordinator.release(&self.service_name).await;
+        self.released = true;
+    }
+}
+
+impl Drop for CoordinatorLockGuard {
+    fn drop(&mut self) {
+        if !self.released {
+            let coordinator = self.coordinator.clone();
+            let service_name = self.service_name.clone();
+            tokio::spawn(async move { coordinator.release(&service_name).await });
+        }
+    }
+}
+
+async fn restart_service_inner(app: &tauri::AppHandle, service_name: String, state: State<'_, AppState>) -> Result<bool, String> {
+    if !state.coordinator.try_acquire(&service_name).await {
+        println!("Another supervisor instance holds the restart lock for {}; skipping", service_name);
+        // This restart has fired without running; clear `scheduled` so a
+        // later supervisor tick can schedule another one.
+        if let Some(policy) = state.process_tracker.lock().unwrap().restart_policies.get_mut(&service_name) {
+            policy.scheduled = false;
+        }
+        return Ok(false);
+    }
+
+    let guard = CoordinatorLockGuard {
+        coordinator: state.coordinator.clone(),
+        service_name: service_name.clone(),
+        released: false,
+    };
+
+    let result = restart_service_locked(app, service_name.clone(), state.clone()).await;
+    guard.release().await;
+    result
+}
+
+async fn restart_service_locked(app: &tauri::AppHandle, service_name: String, state: State<'_, AppState>) -> Result<bool, String> {
+    emit_progress(app, 1, 1, &service_name, "begin");
     stop_service(service_name.clone(), state.clone())?;
+    state.process_tracker.lock().unwrap().set_last_exit_reason(&service_name, "auto_restart");
+    emit_progress(app, 1, 1, &service_name, "waiting_for_health");
     tokio::time::sleep(Duration::from_secs(2)).await;
-    
-    // Increment restart count
+
+    let max_restarts = {
+        let services = state.services.lock().unwrap();
+        services.get(&service_name).map(|s| s.max_restarts).unwrap_or(5)
+    };
+
     {
         let mut tracker = state.process_tracker.lock().unwrap();
-        tracker.increment_restart_count(&service_name);
+        tracker.record_restart_attempt(&service_name, max_restarts);
     }
-    
-    start_service(service_name, state).await
+
+    let result = start_service_inner(app, service_name.clone(), 1, 1, state).await;
+    emit_progress(app, 1, 1, &service_name, "done");
+    result
 }
 
-#[tauri::command]
-async fn start_all_services(state: State<'_, AppState>) -> Result<Vec<String>, String> {
-    let (service_names, dep_counts) = {
-        let services = state.services.lock().unwrap();
-        let names: Vec<String> = services.keys().cloned().collect();
-        let counts: Vec<(String, usize)> = services.iter()
-            .map(|(name, cfg)| (name.clone(), cfg.dependencies.len()))
+/// Kahn's algorithm over `ServiceConfig.dependencies`: seed a queue with
+/// zero-in-degree nodes, repeatedly pop one, append it to the order, and
+/// decrement the in-degree of its dependents. If the queue empties before
+/// every node is emitted, the remaining nodes are in a dependency cycle or
+/// depend on one, and all of them are named in the error.
+fn topological_start_order(services: &HashMap<String, ServiceConfig>) -> Result<Vec<String>, String> {
+    let mut in_degree: HashMap<&str, usize> = services.keys().map(|name| (name.as_str(), 0)).collect();
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for (name, cfg) in services {
+        for dep in &cfg.dependencies {
+            if !services.contains_key(dep) {
+                return Err(format!("service '{}' depends on unknown service '{}'", name, dep));
+            }
+            *in_degree.get_mut(name.as_str()).unwrap() += 1;
+            dependents.entry(dep.as_str()).or_default().push(name.as_str());
+        }
+    }
+
+    let mut queue: std::collections::VecDeque<&str> = in_degree
+        .iter()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(name, _)| *name)
+        .collect();
+    let mut queue_sorted: Vec<&str> = queue.drain(..).collect();
+    queue_sorted.sort();
+    let mut queue: std::collections::VecDeque<&str> = queue_sorted.into();
+
+    let mut order = Vec::with_capacity(services.len());
+    while let Some(name) = queue.pop_front() {
+        order.push(name.to_string());
+        if let Some(deps) = dependents.get(name) {
+            for &dependent in deps {
+                let degree = in_degree.get_mut(dependent).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(dependent);
+                }
+            }
+        }
+    }
+
+    if order.len() != services.len() {
+        let cyclic: Vec<&str> = in_degree
+            .iter()
+            .filter(|(name, degree)| **degree > 0 && !order.contains(&name.to_string()))
+            .map(|(name, _)| *name)
             .collect();
-        (names, counts)
-    }; // MutexGuard dropped here
+        return Err(format!(
+            "dependency cycle detected among services: {}",
+            cyclic.join(", ")
+        ));
+    }
 
-    // Sort by dependencies (services with no deps first)
-    let mut service_names_sorted: Vec<String> = service_names;
-    service_names_sorted.sort_by_key(|name| {
-        dep_counts.iter()
-            .find(|(n, _)| n == name)
-            .map(|(_, count)| *count)
-            .unwrap_or(0)
-    });
+    Ok(order)
+}
+
+#[tauri::command]
+async fn start_all_services(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<Vec<String>, String> {
+    let service_names_sorted = {
+        let services = state.services.lock().unwrap();
+        topological_start_order(&services)?
+    };
 
+    let total = service_names_sorted.len() as u32;
     let mut results = Vec::new();
 
-    for service_name in service_names_sorted {
-        match start_service(service_name.clone(), state.clone()).await {
+    for (idx, service_name) in service_names_sorted.into_iter().enumerate() {
+        let current = idx as u32 + 1;
+        match start_service_inner(&app, service_name.clone(), current, total, state.clone()).await {
             Ok(_) => results.push(format!("{}: started", service_name)),
             Err(e) => results.push(format!("{}: failed - {}", service_name, e)),
         }
         tokio::time::sleep(Duration::from_millis(500)).await;
     }
-    
+
     Ok(results)
 }
 
 #[tauri::command]
 fn stop_all_services(state: State<AppState>) -> Result<Vec<String>, String> {
-    let services = state.services.lock().unwrap();
-    let service_names: Vec<String> = services.keys().cloned().collect();
-    drop(services);
-    
+    let mut service_names_sorted = {
+        let services = state.services.lock().unwrap();
+        topological_start_order(&services)?
+    };
+    // Dependents stop before their dependencies.
+    service_names_sorted.reverse();
+
     let mut results = Vec::new();
-    
-    for service_name in service_names {
+
+    for service_name in service_names_sorted {
         match stop_service(service_name.clone(), state.clone()) {
             Ok(_) => results.push(format!("{}: stopped", service_name)),
             Err(e) => results.push(format!("{}: failed - {}", service_name, e)),
         }
     }
-    
+
     Ok(results)
 }
 
@@ -596,83 +1420,488 @@ fn get_trading_metrics(state: State<AppState>) -> Result<TradingMetrics, String>
     })
 }
 
+/// How stale `read_trading_bot_heartbeat`'s result can be before the
+/// trading-bot circuit breaker counts it as a failure.
+const TRADING_BOT_HEARTBEAT_STALE_SECS: i64 = 300;
+
 #[tauri::command]
 fn get_trading_bot_status(state: State<AppState>) -> Result<serde_json::Value, String> {
-    let status = get_service_status("trading-bot".to_string(), state)?;
-    
+    let status = get_service_status("trading-bot".to_string(), state.clone())?;
+    let last_heartbeat = read_trading_bot_heartbeat(&state.monorepo_path);
+
+    // trading-bot has no health_check_url/port for check_service_health to
+    // probe and isn't in the auto_restart set monitor_auto_restart watches,
+    // so trading.db's own heartbeat is the only liveness signal available —
+    // feed it into the circuit breaker directly rather than leaving the
+    // breaker permanently unfed (and so permanently "CLOSED").
+    let circuit_breaker_status = {
+        let mut tracker = state.process_tracker.lock().unwrap();
+        if trading_bot_heartbeat_is_fresh(last_heartbeat.as_deref()) {
+            tracker.record_circuit_success("trading-bot");
+        } else {
+            tracker.record_circuit_failure("trading-bot");
+        }
+        tracker.circuit_state("trading-bot").as_str().to_string()
+    };
+
     Ok(serde_json::json!({
         "isRunning": status.status == "running",
-        "lastHeartbeat": null,
-        "circuitBreakerStatus": "NORMAL"
+        "lastHeartbeat": last_heartbeat,
+        "circuitBreakerStatus": circuit_breaker_status
     }))
 }
 
+/// Reads `MAX(entry_time)` from `trading.db` as a heartbeat proxy — the most
+/// recent trade activity the bot has recorded. Returns `None` if the
+/// database doesn't exist yet or has no rows.
+fn read_trading_bot_heartbeat(monorepo_path: &std::path::Path) -> Option<String> {
+    let db_path = monorepo_path.join("trading.db");
+    if !db_path.exists() {
+        return None;
+    }
+    let conn = rusqlite::Connection::open(&db_path).ok()?;
+    conn.query_row("SELECT MAX(entry_time) FROM trades", [], |row| row.get(0)).ok()
+}
+
+/// Counts a heartbeat as healthy if it parses (RFC3339 or SQLite's default
+/// `YYYY-MM-DD HH:MM:SS`) and falls within `TRADING_BOT_HEARTBEAT_STALE_SECS`
+/// of now; a missing, unparseable, or stale heartbeat counts as a failure.
+fn trading_bot_heartbeat_is_fresh(last_heartbeat: Option<&str>) -> bool {
+    let Some(last_heartbeat) = last_heartbeat else { return false };
+
+    let parsed = chrono::DateTime::parse_from_rfc3339(last_heartbeat)
+        .map(|dt| dt.with_timezone(&Utc))
+        .or_else(|_| {
+            chrono::NaiveDateTime::parse_from_str(last_heartbeat, "%Y-%m-%d %H:%M:%S")
+                .map(|naive| Utc.from_utc_datetime(&naive))
+        });
+
+    match parsed {
+        Ok(ts) => (Utc::now() - ts).num_seconds() < TRADING_BOT_HEARTBEAT_STALE_SECS,
+        Err(_) => false,
+    }
+}
+
+// Python `logging` default: "2024-03-01 12:34:56,789 LEVEL message"
+static PY_LOGGING_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<ts>\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2},\d{3})\s+(?P<level>[A-Za-z]+)\s+(?P<msg>.*)$").unwrap()
+});
+// RFC3339-prefixed lines, with an optional level token right after the timestamp.
+static RFC3339_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<ts>\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(?:\.\d+)?(?:Z|[+-]\d{2}:\d{2}))\s+(?:\[?(?P<level>[A-Za-z]+)\]?\s+)?(?P<msg>.*)$").unwrap()
+});
+// Bracketed level token with no recognizable timestamp, e.g. "[ERROR] message".
+static BRACKETED_LEVEL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\[(?P<level>[A-Za-z]+)\]\s*(?P<msg>.*)$").unwrap()
+});
+
+/// Tries each known log format in order and returns `(timestamp, level, message)`
+/// for lines that look like the start of a new entry. Returns `None` for
+/// continuation lines (e.g. stack trace frames) so the caller can append them
+/// to the previous entry instead of minting a new one.
+fn parse_log_line(line: &str) -> Option<(String, String, String)> {
+    if let Some(caps) = PY_LOGGING_RE.captures(line) {
+        let timestamp = chrono::NaiveDateTime::parse_from_str(&caps["ts"].replacen(',', ".", 1), "%Y-%m-%d %H:%M:%S%.3f")
+            .map(|dt| Utc.from_utc_datetime(&dt).to_rfc3339())
+            .unwrap_or_else(|_| caps["ts"].to_string());
+        return Some((timestamp, caps["level"].to_uppercase(), caps["msg"].to_string()));
+    }
+    if let Some(caps) = RFC3339_RE.captures(line) {
+        let level = caps.name("level").map(|m| m.as_str().to_uppercase()).unwrap_or_else(|| "INFO".to_string());
+        return Some((caps["ts"].to_string(), level, caps["msg"].to_string()));
+    }
+    if let Some(caps) = BRACKETED_LEVEL_RE.captures(line) {
+        return Some((Utc::now().to_rfc3339(), caps["level"].to_uppercase(), caps["msg"].to_string()));
+    }
+    None
+}
+
+/// Parses raw log text into entries, folding lines that don't match a known
+/// format (stack trace continuations, wrapped messages) into the previous
+/// entry rather than treating them as their own unlabeled log line.
+fn parse_log_entries(contents: &str, source: &str) -> Vec<LogEntry> {
+    let mut entries: Vec<LogEntry> = Vec::new();
+
+    for line in contents.lines() {
+        match parse_log_line(line) {
+            Some((timestamp, level, message)) => entries.push(LogEntry {
+                timestamp,
+                level,
+                message,
+                source: source.to_string(),
+            }),
+            None => match entries.last_mut() {
+                Some(last) => {
+                    last.message.push('\n');
+                    last.message.push_str(line);
+                }
+                None => entries.push(LogEntry {
+                    timestamp: Utc::now().to_rfc3339(),
+                    level: "INFO".to_string(),
+                    message: line.to_string(),
+                    source: source.to_string(),
+                }),
+            },
+        }
+    }
+
+    entries
+}
+
 #[tauri::command]
-fn get_tail_logs(service_name: String, lines: usize, _state: State<AppState>) -> Result<Vec<LogEntry>, String> {
-    let log_path = match service_name.as_str() {
-        "trading-bot" => PathBuf::from("C:\\dev\\trading_new.log"),
-        "web-app" => PathBuf::from("C:\\dev\\vite-web-app.log"),
-        "backend" => PathBuf::from("C:\\dev\\backend\\backend.log"),
-        "booking-platform" => PathBuf::from("C:\\dev\\projects\\active\\web-apps\\business-booking-platform\\vite.log"),
-        "content-builder" => PathBuf::from("C:\\dev\\projects\\active\\web-apps\\digital-content-builder\\vite.log"),
-        _ => return Err(format!("Unknown service: {}", service_name)),
+fn get_tail_logs(
+    service_name: String,
+    lines: usize,
+    level_filter: Option<String>,
+    state: State<AppState>,
+) -> Result<Vec<LogEntry>, String> {
+    let log_path = {
+        let services = state.services.lock().unwrap();
+        let service = services.get(&service_name)
+            .ok_or_else(|| format!("Unknown service: {}", service_name))?;
+        service.log_path.clone()
+            .ok_or_else(|| format!("Service {} has no configured log_path", service_name))?
     };
-    
+
     if !log_path.exists() {
         return Ok(Vec::new());
     }
-    
-    // Read last N lines from log file
+
     let contents = std::fs::read_to_string(&log_path)
         .map_err(|e| format!("Failed to read log file: {}", e))?;
-    
-    let mut log_entries = Vec::new();
-    let log_lines: Vec<&str> = contents.lines().rev().take(lines).collect();
-    
-    for line in log_lines.iter().rev() {
-        // Basic log parsing - adjust based on actual log format
-        log_entries.push(LogEntry {
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            level: "INFO".to_string(),
-            message: line.to_string(),
-            source: service_name.clone(),
-        });
+
+    let mut entries = parse_log_entries(&contents, &service_name);
+
+    if let Some(level) = level_filter {
+        let level = level.to_uppercase();
+        entries.retain(|entry| entry.level == level);
     }
-    
-    Ok(log_entries)
+
+    let skip = entries.len().saturating_sub(lines);
+    Ok(entries.split_off(skip))
 }
 
 #[tauri::command]
-fn clear_logs(service_name: String, _state: State<AppState>) -> Result<bool, String> {
-    let log_path = match service_name.as_str() {
-        "trading-bot" => PathBuf::from("C:\\dev\\trading_new.log"),
-        "web-app" => PathBuf::from("C:\\dev\\vite-web-app.log"),
-        "backend" => PathBuf::from("C:\\dev\\backend\\backend.log"),
-        "booking-platform" => PathBuf::from("C:\\dev\\projects\\active\\web-apps\\business-booking-platform\\vite.log"),
-        "content-builder" => PathBuf::from("C:\\dev\\projects\\active\\web-apps\\digital-content-builder\\vite.log"),
-        _ => return Err(format!("Unknown service: {}", service_name)),
+fn clear_logs(service_name: String, state: State<AppState>) -> Result<bool, String> {
+    let log_path = {
+        let services = state.services.lock().unwrap();
+        let service = services.get(&service_name)
+            .ok_or_else(|| format!("Unknown service: {}", service_name))?;
+        service.log_path.clone()
+            .ok_or_else(|| format!("Service {} has no configured log_path", service_name))?
     };
     
     std::fs::write(&log_path, "")
         .map_err(|e| format!("Failed to clear log file: {}", e))?;
-    
+
     Ok(true)
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "action")]
+enum WorkloadStep {
+    Start { service: String },
+    WaitHealthy { service: String, timeout_secs: u64 },
+    Load { service: String, concurrency: u32, requests: u32 },
+    Sleep { seconds: u64 },
+    Stop { service: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Workload {
+    name: String,
+    steps: Vec<WorkloadStep>,
+    results_endpoint: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StepReport {
+    step: String,
+    service: String,
+    latency_ms: u64,
+    cpu_usage: f32,
+    memory_usage: u64,
+    success: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkloadReport {
+    workload_name: String,
+    started_at: String,
+    total_duration_ms: u64,
+    steps: Vec<StepReport>,
+}
+
+#[tauri::command]
+async fn run_workload(app: tauri::AppHandle, path: String, state: State<'_, AppState>) -> Result<WorkloadReport, String> {
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read workload file: {}", e))?;
+    let workload: Workload = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse workload file: {}", e))?;
+
+    let started_at = chrono::Utc::now().to_rfc3339();
+    let run_started = std::time::Instant::now();
+    let total = workload.steps.len() as u32;
+    let mut step_reports = Vec::new();
+
+    for (idx, step) in workload.steps.iter().enumerate() {
+        let current = idx as u32 + 1;
+        let step_started = std::time::Instant::now();
+        let (label, service, result) = match step {
+            WorkloadStep::Start { service } => {
+                let r = start_service_inner(&app, service.clone(), current, total, state.clone()).await.map(|_| ());
+                ("start".to_string(), service.clone(), r)
+            }
+            WorkloadStep::WaitHealthy { service, timeout_secs } => {
+                emit_progress(&app, current, total, service, "waiting_for_health");
+                let r = wait_until_healthy(service.clone(), *timeout_secs, state.clone()).await;
+                ("wait_healthy".to_string(), service.clone(), r)
+            }
+            WorkloadStep::Load { service, concurrency, requests } => {
+                let r = fire_load(service.clone(), *concurrency, *requests, state.clone()).await;
+                ("load".to_string(), service.clone(), r)
+            }
+            WorkloadStep::Sleep { seconds } => {
+                tokio::time::sleep(Duration::from_secs(*seconds)).await;
+                ("sleep".to_string(), String::new(), Ok(()))
+            }
+            WorkloadStep::Stop { service } => {
+                let r = stop_service(service.clone(), state.clone()).map(|_| ());
+                ("stop".to_string(), service.clone(), r)
+            }
+        };
+
+        let (cpu_usage, memory_usage) = if service.is_empty() {
+            (0.0, 0)
+        } else {
+            get_service_status(service.clone(), state.clone())
+                .map(|s| (s.cpu_usage, s.memory_usage))
+                .unwrap_or((0.0, 0))
+        };
+
+        step_reports.push(StepReport {
+            step: label,
+            service,
+            latency_ms: step_started.elapsed().as_millis() as u64,
+            cpu_usage,
+            memory_usage,
+            success: result.is_ok(),
+            error: result.err(),
+        });
+    }
+
+    let report = WorkloadReport {
+        workload_name: workload.name,
+        started_at,
+        total_duration_ms: run_started.elapsed().as_millis() as u64,
+        steps: step_reports,
+    };
+
+    if let Some(endpoint) = &workload.results_endpoint {
+        if let Err(e) = reqwest::Client::new().post(endpoint).json(&report).send().await {
+            eprintln!("Failed to POST workload report to {}: {}", endpoint, e);
+        }
+    }
+
+    Ok(report)
+}
+
+async fn wait_until_healthy(service_name: String, timeout_secs: u64, state: State<'_, AppState>) -> Result<(), String> {
+    let deadline = std::time::Instant::now() + Duration::from_secs(timeout_secs);
+    loop {
+        let health = check_service_health(service_name.clone(), state.clone()).await?;
+        if health == "healthy" {
+            return Ok(());
+        }
+        if std::time::Instant::now() >= deadline {
+            return Err(format!("Service {} did not become healthy within {}s", service_name, timeout_secs));
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+}
+
+async fn fire_load(service_name: String, concurrency: u32, requests: u32, state: State<'_, AppState>) -> Result<(), String> {
+    if concurrency == 0 {
+        return Err("concurrency must be at least 1".to_string());
+    }
+
+    let health_check_url = {
+        let services = state.services.lock().unwrap();
+        services
+            .get(&service_name)
+            .and_then(|s| s.health_check_url.clone())
+            .ok_or_else(|| format!("Service {} has no health_check_url to load-test", service_name))?
+    };
+
+    let client = reqwest::Client::new();
+    let mut remaining = requests;
+    while remaining > 0 {
+        let batch = remaining.min(concurrency);
+        let mut handles = Vec::new();
+        for _ in 0..batch {
+            let client = client.clone();
+            let url = health_check_url.clone();
+            handles.push(tokio::spawn(async move { client.get(&url).send().await }));
+        }
+        for handle in handles {
+            let _ = handle.await;
+        }
+        remaining -= batch;
+    }
+
+    Ok(())
+}
+
+/// Name of the env var that turns on the admin metrics listener. Unset by
+/// default so nothing binds a localhost port unless an operator opts in.
+const METRICS_PORT_ENV_VAR: &str = "DESKTOP_COMMANDER_METRICS_PORT";
+
+/// Starts a small blocking HTTP admin listener on `127.0.0.1:<port>` serving
+/// `/metrics` (Prometheus text exposition) and `/status` (the
+/// `get_all_services_status` payload as JSON), if `DESKTOP_COMMANDER_METRICS_PORT`
+/// is set. Off by default for security.
+fn maybe_start_metrics_server(app_handle: tauri::AppHandle) {
+    let port: u16 = match std::env::var(METRICS_PORT_ENV_VAR).ok().and_then(|v| v.parse().ok()) {
+        Some(port) => port,
+        None => return,
+    };
+
+    thread::spawn(move || {
+        let listener = match std::net::TcpListener::bind(("127.0.0.1", port)) {
+            Ok(listener) => listener,
+            Err(e) => {
+                eprintln!("Failed to bind metrics listener on 127.0.0.1:{}: {}", port, e);
+                return;
+            }
+        };
+        println!("Metrics endpoint listening on http://127.0.0.1:{}/metrics", port);
+
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            // Spawn per connection so one slow client reading its response
+            // slowly (or a caller that never reads at all) can't block every
+            // future scrape behind it.
+            let app_handle = app_handle.clone();
+            thread::spawn(move || {
+                if let Some(state) = app_handle.try_state::<AppState>() {
+                    handle_metrics_request(stream, state);
+                }
+            });
+        }
+    });
+}
+
+fn handle_metrics_request(mut stream: std::net::TcpStream, state: State<AppState>) {
+    use std::io::{Read, Write};
+
+    let mut buf = [0u8; 1024];
+    let n = match stream.read(&mut buf) {
+        Ok(n) => n,
+        Err(_) => return,
+    };
+    let request = String::from_utf8_lossy(&buf[..n]);
+    let path = request
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/");
+
+    let (status_line, content_type, body) = match path {
+        "/metrics" => ("200 OK", "text/plain; version=0.0.4", render_prometheus_metrics(state)),
+        "/status" => ("200 OK", "application/json", render_status_json(state)),
+        _ => ("404 Not Found", "text/plain", "not found\n".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status_line,
+        content_type,
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+fn render_prometheus_metrics(state: State<AppState>) -> String {
+    let statuses = get_all_services_status(state).unwrap_or_default();
+    let mut out = String::new();
+
+    out.push_str("# HELP service_cpu_percent CPU usage percent reported by the process tracker\n");
+    out.push_str("# TYPE service_cpu_percent gauge\n");
+    for s in &statuses {
+        out.push_str(&format!("service_cpu_percent{{name=\"{}\"}} {}\n", s.name, s.cpu_usage));
+    }
+
+    out.push_str("# HELP service_memory_usage_mb Resident memory usage in megabytes\n");
+    out.push_str("# TYPE service_memory_usage_mb gauge\n");
+    for s in &statuses {
+        out.push_str(&format!("service_memory_usage_mb{{name=\"{}\"}} {}\n", s.name, s.memory_usage));
+    }
+
+    out.push_str("# HELP service_uptime_seconds Seconds since the tracked process was last started\n");
+    out.push_str("# TYPE service_uptime_seconds gauge\n");
+    for s in &statuses {
+        out.push_str(&format!("service_uptime_seconds{{name=\"{}\"}} {}\n", s.name, s.uptime.unwrap_or(0)));
+    }
+
+    out.push_str("# HELP service_restart_count Restart attempts recorded for this service\n");
+    out.push_str("# TYPE service_restart_count counter\n");
+    for s in &statuses {
+        out.push_str(&format!("service_restart_count{{name=\"{}\"}} {}\n", s.name, s.restart_count));
+    }
+
+    out.push_str("# HELP service_up Whether the tracked process is currently running\n");
+    out.push_str("# TYPE service_up gauge\n");
+    for s in &statuses {
+        out.push_str(&format!("service_up{{name=\"{}\"}} {}\n", s.name, if s.status == "running" { 1 } else { 0 }));
+    }
+
+    let runtime = collect_runtime_metrics();
+    out.push_str("# HELP tokio_worker_threads Number of worker threads used by the Tokio runtime\n");
+    out.push_str("# TYPE tokio_worker_threads gauge\n");
+    out.push_str(&format!("tokio_worker_threads {}\n", runtime.worker_threads));
+
+    out.push_str("# HELP tokio_blocking_threads Number of additional threads spawned for blocking operations\n");
+    out.push_str("# TYPE tokio_blocking_threads gauge\n");
+    out.push_str(&format!("tokio_blocking_threads {}\n", runtime.blocking_threads));
+
+    out.push_str("# HELP tokio_alive_tasks Number of alive tasks in the Tokio runtime\n");
+    out.push_str("# TYPE tokio_alive_tasks gauge\n");
+    out.push_str(&format!("tokio_alive_tasks {}\n", runtime.alive_tasks));
+
+    out
+}
+
+fn render_status_json(state: State<AppState>) -> String {
+    let statuses = get_all_services_status(state).unwrap_or_default();
+    serde_json::to_string(&statuses).unwrap_or_else(|_| "[]".to_string())
+}
+
 fn main() {
-    let app_state = AppState::new();
-    
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
-        .manage(app_state)
         .setup(|app| {
+            let config_dir = app.path().app_config_dir()
+                .expect("failed to resolve app config dir");
+            let config_path = config_dir.join(SERVICES_CONFIG_FILENAME);
+            let services_file = load_services_file(&config_path)
+                .expect("failed to load services config");
+            app.manage(AppState::new(services_file, config_path));
+
             let app_handle = app.handle().clone();
-            
+            let runtime_handle = tokio::runtime::Handle::current();
+
             // Start auto-restart monitoring thread
             thread::spawn(move || {
-                monitor_auto_restart(app_handle);
+                monitor_auto_restart(app_handle, runtime_handle);
             });
-            
+
+            maybe_start_metrics_server(app.handle().clone());
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -688,41 +1917,116 @@ fn main() {
             get_trading_metrics,
             get_trading_bot_status,
             get_tail_logs,
-            clear_logs
+            clear_logs,
+            run_workload,
+            reload_config,
+            metrics_snapshot
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            // On app exit, cancel the shutdown token (aborting any in-flight
+            // restart) and block the exit until outstanding restart tasks
+            // have wound down or hit `shutdown()`'s bounded timeout.
+            if let tauri::RunEvent::ExitRequested { .. } = event {
+                if let Some(state) = app_handle.try_state::<AppState>() {
+                    tauri::async_runtime::block_on(state.shutdown());
+                }
+            }
+        });
 }
 
 // Auto-restart monitoring
-fn monitor_auto_restart(app_handle: tauri::AppHandle) {
+fn monitor_auto_restart(app_handle: tauri::AppHandle, runtime_handle: tokio::runtime::Handle) {
     loop {
         thread::sleep(Duration::from_secs(10));
-        
+
         if let Some(state) = app_handle.try_state::<AppState>() {
             let services = state.services.lock().unwrap();
-            let service_names: Vec<(String, bool)> = services.iter()
+            let service_names: Vec<(String, u32)> = services.iter()
                 .filter(|(_, cfg)| cfg.auto_restart)
-                .map(|(name, _)| (name.clone(), true))
+                .map(|(name, cfg)| (name.clone(), cfg.max_restarts))
                 .collect();
             drop(services);
-            
-            for (service_name, _) in service_names {
-                // Check if service is running
-                if let Ok(status) = get_service_status(service_name.clone(), state.clone()) {
-                    if status.status != "running" {
-                        println!("Auto-restarting service: {}", service_name);
-                        
-                        // Use tokio runtime for async restart
-                        let runtime = tokio::runtime::Runtime::new().unwrap();
-                        let state_clone = state.clone();
-                        let service_name_clone = service_name.clone();
-                        
-                        runtime.block_on(async {
-                            let _ = restart_service(service_name_clone, state_clone).await;
-                        });
-                    }
+
+            if state.shutdown_token.is_cancelled() {
+                // Supervisor is shutting down: stop scheduling new restarts,
+                // but let already-spawned ones run until `shutdown()`'s
+                // timeout reaps them.
+                continue;
+            }
+
+            for (service_name, max_restarts) in service_names {
+                let status = match get_service_status(service_name.clone(), state.clone()) {
+                    Ok(status) => status,
+                    Err(_) => continue,
+                };
+
+                if status.status == "running" {
+                    let mut tracker = state.process_tracker.lock().unwrap();
+                    tracker.maybe_reset_after_cooldown(&service_name);
+                    tracker.record_circuit_success(&service_name);
+                    continue;
                 }
+
+                {
+                    let mut tracker = state.process_tracker.lock().unwrap();
+                    tracker.record_circuit_failure(&service_name);
+                }
+
+                let circuit_status = {
+                    let mut tracker = state.process_tracker.lock().unwrap();
+                    tracker.circuit_state(&service_name)
+                };
+
+                if !circuit_status.allows_restart() {
+                    println!("Service {} circuit breaker is {}; refusing auto-restart", service_name, circuit_status.as_str());
+                    continue;
+                }
+
+                let policy = {
+                    let tracker = state.process_tracker.lock().unwrap();
+                    tracker.restart_policy(&service_name)
+                };
+
+                if policy.is_failed() {
+                    println!("Service {} restart policy is Failed after exceeding {} attempts in the window; skipping auto-restart", service_name, max_restarts);
+                    continue;
+                }
+
+                if policy.scheduled {
+                    // Already waiting out its backoff from a previous tick of this loop.
+                    continue;
+                }
+
+                let delay = policy.remaining_backoff();
+                {
+                    let mut tracker = state.process_tracker.lock().unwrap();
+                    tracker.mark_restart_scheduled(&service_name);
+                }
+
+                println!("Scheduling auto-restart for {} in {:?}", service_name, delay);
+
+                let state_clone = state.clone();
+                let service_name_clone = service_name.clone();
+                let app_handle_clone = app_handle.clone();
+
+                // This loop runs on its own OS thread rather than inside a Tokio
+                // reactor, so `try_current()` normally falls through to the
+                // shared handle captured at startup — but fall back gracefully
+                // if we're ever invoked from a thread that already has one,
+                // rather than constructing (and leaking) a fresh runtime per
+                // restart. Dispatching via `spawn` also lets restarts for
+                // different services run concurrently, and lets this one wait
+                // out its backoff on a Tokio timer instead of firing immediately.
+                let handle = tokio::runtime::Handle::try_current().unwrap_or_else(|_| runtime_handle.clone());
+                let join_handle = handle.spawn(async move {
+                    if !delay.is_zero() {
+                        tokio::time::sleep(delay).await;
+                    }
+                    let _ = restart_service(app_handle_clone, service_name_clone, state_clone).await;
+                });
+                state.restart_tasks.lock().unwrap().push(join_handle);
             }
         }
     }
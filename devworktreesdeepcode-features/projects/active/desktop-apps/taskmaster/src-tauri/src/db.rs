@@ -1,24 +1,121 @@
 use anyhow::Result;
-use sqlx::{sqlite::SqlitePoolOptions, Pool, Sqlite};
-use std::fs;
+use sqlx::migrate::Migrator;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::{Pool, Sqlite};
+use std::env;
+use std::str::FromStr;
+use tauri::Manager;
 
 pub type DbPool = Pool<Sqlite>;
 
-pub async fn init_db(_app_handle: &tauri::AppHandle) -> Result<DbPool> {
-    // Use a fixed path for now, will improve later
-    let app_dir = std::env::temp_dir().join("vibepilot").join("db");
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
 
-    fs::create_dir_all(&app_dir)?;
+struct PoolSettings {
+    max_connections: u32,
+    busy_timeout_ms: u64,
+}
+
+impl PoolSettings {
+    fn from_env() -> Self {
+        Self {
+            max_connections: env::var("VIBEPILOT_DB_MAX_CONNECTIONS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+            busy_timeout_ms: env::var("VIBEPILOT_DB_BUSY_TIMEOUT_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5_000),
+        }
+    }
+}
+
+/// Resolves the database under the Tauri app's data directory, applies
+/// WAL mode and foreign-key enforcement on every new connection, and runs
+/// pending migrations before handing back the pool.
+pub async fn init_db(app_handle: &tauri::AppHandle) -> Result<DbPool> {
+    let app_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| anyhow::anyhow!("failed to resolve app data dir: {}", e))?;
+
+    std::fs::create_dir_all(&app_dir)?;
 
     let db_path = app_dir.join("vibepilot.sqlite");
-    let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+    let pool = connect(&db_path).await?;
+
+    MIGRATOR.run(&pool).await?;
+
+    Ok(pool)
+}
+
+async fn connect(db_path: &std::path::Path) -> Result<DbPool> {
+    let settings = PoolSettings::from_env();
+
+    let connect_options = SqliteConnectOptions::from_str(&format!("sqlite:{}", db_path.display()))?
+        .create_if_missing(true)
+        .busy_timeout(std::time::Duration::from_millis(settings.busy_timeout_ms))
+        .pragma("journal_mode", "WAL")
+        .pragma("foreign_keys", "ON");
 
     let pool = SqlitePoolOptions::new()
-        .max_connections(5)
-        .connect(&db_url)
+        .max_connections(settings.max_connections)
+        .connect_with(connect_options)
         .await?;
 
-    sqlx::migrate!("./migrations").run(&pool).await?;
-
     Ok(pool)
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DbStatus {
+    pub current_version: Option<i64>,
+    pub pending_migrations: Vec<String>,
+}
+
+#[tauri::command]
+pub async fn db_status(pool: tauri::State<'_, DbPool>) -> Result<DbStatus, String> {
+    let applied: Vec<i64> = sqlx::query_scalar("SELECT version FROM _sqlx_migrations ORDER BY version")
+        .fetch_all(pool.inner())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let current_version = applied.last().copied();
+    let pending_migrations = MIGRATOR
+        .iter()
+        .filter(|m| !applied.contains(&m.version))
+        .map(|m| m.description.to_string())
+        .collect();
+
+    Ok(DbStatus {
+        current_version,
+        pending_migrations,
+    })
+}
+
+/// Drops every application table and re-runs migrations from scratch.
+/// Callers must pass `confirm: true` to guard against accidental data loss.
+#[tauri::command]
+pub async fn db_reset(pool: tauri::State<'_, DbPool>, confirm: bool) -> Result<(), String> {
+    if !confirm {
+        return Err("db_reset requires confirm=true".to_string());
+    }
+
+    for table in ["attachments", "sessions", "tasks", "settings", "notifications"] {
+        sqlx::query(&format!("DROP TABLE IF EXISTS {}", table))
+            .execute(pool.inner())
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    sqlx::query("DROP TABLE IF EXISTS _sqlx_migrations")
+        .execute(pool.inner())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    MIGRATOR.run(pool.inner()).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn db_vacuum(pool: tauri::State<'_, DbPool>) -> Result<(), String> {
+    sqlx::query("VACUUM").execute(pool.inner()).await.map_err(|e| e.to_string())?;
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{FromRow, Pool, Sqlite};
+use std::time::Duration;
+use tauri::State;
+use tracing::{debug, error, info, warn};
+
+pub type JobPool = Pool<Sqlite>;
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+const REAP_INTERVAL: Duration = Duration::from_secs(10);
+const HEARTBEAT_TIMEOUT_SECS: i64 = 30;
+const MAX_ATTEMPTS: i64 = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Job {
+    pub id: String,
+    pub queue: String,
+    pub payload: String,
+    pub status: String,
+    pub attempts: i64,
+    pub heartbeat: Option<String>,
+    pub created_at: String,
+}
+
+pub async fn init_jobs(pool: &JobPool) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS jobs (
+            id TEXT PRIMARY KEY,
+            queue TEXT NOT NULL,
+            payload TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'new',
+            attempts INTEGER NOT NULL DEFAULT 0,
+            heartbeat TEXT,
+            created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_jobs_status_heartbeat ON jobs (status, heartbeat)")
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn enqueue_job(
+    pool: State<'_, JobPool>,
+    queue: String,
+    payload: serde_json::Value,
+) -> Result<Job, String> {
+    let id = nanoid::nanoid!(12);
+    let payload = payload.to_string();
+
+    sqlx::query("INSERT INTO jobs (id, queue, payload) VALUES (?1, ?2, ?3)")
+        .bind(&id)
+        .bind(&queue)
+        .bind(&payload)
+        .execute(pool.inner())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    sqlx::query_as::<_, Job>("SELECT * FROM jobs WHERE id = ?1")
+        .bind(id)
+        .fetch_one(pool.inner())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_jobs(pool: State<'_, JobPool>, queue: Option<String>) -> Result<Vec<Job>, String> {
+    let query = if let Some(queue) = queue {
+        sqlx::query_as::<_, Job>("SELECT * FROM jobs WHERE queue = ?1 ORDER BY created_at DESC")
+            .bind(queue)
+    } else {
+        sqlx::query_as::<_, Job>("SELECT * FROM jobs ORDER BY created_at DESC")
+    };
+
+    query.fetch_all(pool.inner()).await.map_err(|e| e.to_string())
+}
+
+async fn claim_job(pool: &JobPool) -> Result<Option<Job>, sqlx::Error> {
+    let now = chrono::Utc::now().to_rfc3339();
+
+    sqlx::query_as::<_, Job>(
+        r#"
+        UPDATE jobs
+        SET status = 'running', heartbeat = ?1
+        WHERE id = (SELECT id FROM jobs WHERE status = 'new' ORDER BY created_at LIMIT 1)
+        RETURNING *
+        "#,
+    )
+    .bind(now)
+    .fetch_optional(pool)
+    .await
+}
+
+async fn heartbeat_job(pool: &JobPool, id: &str) -> Result<(), sqlx::Error> {
+    let now = chrono::Utc::now().to_rfc3339();
+    sqlx::query("UPDATE jobs SET heartbeat = ?1 WHERE id = ?2 AND status = 'running'")
+        .bind(now)
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+async fn finish_job(pool: &JobPool, id: &str, status: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE jobs SET status = ?1, heartbeat = NULL WHERE id = ?2")
+        .bind(status)
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Resets `running` jobs whose heartbeat has gone stale back to `new`, failing
+/// them outright once they've exhausted `MAX_ATTEMPTS`.
+async fn reap_stale_jobs(pool: &JobPool) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE jobs
+        SET status = 'failed'
+        WHERE status = 'running'
+          AND heartbeat IS NOT NULL
+          AND (julianday('now') - julianday(heartbeat)) * 86400 > ?1
+          AND attempts + 1 >= ?2
+        "#,
+    )
+    .bind(HEARTBEAT_TIMEOUT_SECS as f64)
+    .bind(MAX_ATTEMPTS)
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE jobs
+        SET status = 'new', heartbeat = NULL, attempts = attempts + 1
+        WHERE status = 'running'
+          AND heartbeat IS NOT NULL
+          AND (julianday('now') - julianday(heartbeat)) * 86400 > ?1
+        "#,
+    )
+    .bind(HEARTBEAT_TIMEOUT_SECS as f64)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Background worker loop: claims one job at a time, refreshes its heartbeat
+/// while handling it, and leans on `reap_stale_jobs` to recover abandoned work.
+pub fn spawn_worker(pool: JobPool, runner_config: crate::runner::RunnerConfig) {
+    tokio::spawn(async move {
+        let mut reap_tick = tokio::time::interval(REAP_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = reap_tick.tick() => {
+                    if let Err(e) = reap_stale_jobs(&pool).await {
+                        error!("job reaper failed: {}", e);
+                    }
+                }
+                claimed = claim_job(&pool) => {
+                    match claimed {
+                        Ok(Some(job)) => {
+                            info!("claimed job {} on queue {}", job.id, job.queue);
+                            run_job(&pool, job, &runner_config).await;
+                        }
+                        Ok(None) => {
+                            tokio::time::sleep(Duration::from_secs(1)).await;
+                        }
+                        Err(e) => {
+                            error!("failed to claim job: {}", e);
+                            tokio::time::sleep(Duration::from_secs(1)).await;
+                        }
+                    }
+                }
+            }
+        }
+    });
+}
+
+async fn run_job(pool: &JobPool, job: Job, runner_config: &crate::runner::RunnerConfig) {
+    let pool_hb = pool.clone();
+    let id_hb = job.id.clone();
+    let heartbeat_handle = tokio::spawn(async move {
+        let mut tick = tokio::time::interval(HEARTBEAT_INTERVAL);
+        loop {
+            tick.tick().await;
+            if let Err(e) = heartbeat_job(&pool_hb, &id_hb).await {
+                warn!("heartbeat update failed for job {}: {}", id_hb, e);
+            }
+        }
+    });
+
+    let result = execute_job(&job, runner_config).await;
+    heartbeat_handle.abort();
+
+    let status = match result {
+        Ok(()) => "done",
+        Err(e) => {
+            error!("job {} failed: {}", job.id, e);
+            "failed"
+        }
+    };
+
+    if let Err(e) = finish_job(pool, &job.id, status).await {
+        error!("failed to finalize job {}: {}", job.id, e);
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CodeExecutionPayload {
+    language: String,
+    code: String,
+}
+
+/// Dispatches a claimed job to its handler based on `queue`. Unknown queues
+/// fail immediately rather than being silently dropped.
+async fn execute_job(job: &Job, runner_config: &crate::runner::RunnerConfig) -> Result<(), String> {
+    debug!("executing job {} on queue {}", job.id, job.queue);
+    match job.queue.as_str() {
+        "code_execution" => {
+            let payload: CodeExecutionPayload = serde_json::from_str(&job.payload)
+                .map_err(|e| format!("invalid code_execution payload: {}", e))?;
+            crate::runner::run(runner_config, &payload.language, &payload.code).await?;
+            Ok(())
+        }
+        // No web-search client or learning pipeline exists in this tree yet;
+        // fail explicitly rather than reporting a job as `done` when nothing
+        // actually ran.
+        "web_search" | "learning" => Err(format!("no handler implemented yet for queue '{}'", job.queue)),
+        other => Err(format!("no handler registered for queue '{}'", other)),
+    }
+}
+
+pub async fn connect(db_path: &std::path::Path) -> Result<JobPool, sqlx::Error> {
+    let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+    let pool = SqlitePoolOptions::new().max_connections(5).connect(&db_url).await?;
+    init_jobs(&pool).await?;
+    Ok(pool)
+}
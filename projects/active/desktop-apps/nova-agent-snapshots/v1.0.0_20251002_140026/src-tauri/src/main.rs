@@ -1,6 +1,12 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use tauri::State;
+mod jobs;
+mod memory;
+mod phase;
+mod runner;
+
+use phase::AgentPhase;
+use tauri::{AppHandle, State};
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex as AsyncMutex;
 use tracing::{info, error, debug};
@@ -137,34 +143,96 @@ async fn call_deepseek(config: &Config, user_message: &str) -> Result<String, St
     }
 }
 
+/// Looks for the first fenced code block (```` ```lang ... ``` ````) whose
+/// language tag is in `runner_config`'s allow-list, so a response that
+/// contains runnable code can be dispatched to the job queue instead of
+/// just being displayed as text.
+fn extract_runnable_code_block(response: &str, runner_config: &runner::RunnerConfig) -> Option<(String, String)> {
+    let mut rest = response;
+    while let Some(start) = rest.find("```") {
+        let after_fence = &rest[start + 3..];
+        let newline = after_fence.find('\n')?;
+        let lang = after_fence[..newline].trim().to_lowercase();
+        let body = &after_fence[newline + 1..];
+        let end = body.find("```")?;
+
+        if runner_config.languages.contains_key(&lang) {
+            return Some((lang, body[..end].to_string()));
+        }
+
+        rest = &body[end + 3..];
+    }
+    None
+}
+
 #[tauri::command]
 async fn chat_with_agent(
+    app: AppHandle,
     message: String,
-    _project_id: Option<String>,
+    project_id: Option<String>,
     state: State<'_, AppState>,
     config: State<'_, Config>,
+    memory_pool: State<'_, memory::MemoryPool>,
+    phase_pool: State<'_, phase::PhasePool>,
+    phase_state: State<'_, phase::PhaseState>,
+    job_pool: State<'_, jobs::JobPool>,
+    runner_config: State<'_, runner::RunnerConfig>,
 ) -> Result<String, String> {
     debug!("Received chat message: {}", message);
 
+    phase::transition(&app, &phase_pool, &phase_state, AgentPhase::Thinking, "chat_with_agent: calling DeepSeek").await?;
+
     let mut agent_state = state.lock().await;
 
-    match call_deepseek(&config, &message).await {
+    let outcome = call_deepseek(&config, &message).await;
+
+    match outcome {
         Ok(response) => {
-            agent_state.active_conversations.push(message);
+            agent_state.active_conversations.push(message.clone());
+
+            memory::record_turn(&memory_pool, "user", &message, project_id.as_deref())
+                .await
+                .map_err(|e| e.to_string())?;
+            memory::record_turn(&memory_pool, "assistant", &response, project_id.as_deref())
+                .await
+                .map_err(|e| e.to_string())?;
+
+            if let Some((language, code)) = extract_runnable_code_block(&response, &runner_config) {
+                phase::transition(&app, &phase_pool, &phase_state, AgentPhase::CallingTool, "chat_with_agent: dispatching code_execution job").await?;
+
+                let payload = serde_json::json!({ "language": language, "code": code });
+                match jobs::enqueue_job(job_pool, "code_execution".to_string(), payload).await {
+                    Ok(job) => {
+                        phase::transition(&app, &phase_pool, &phase_state, AgentPhase::Executing, &format!("chat_with_agent: running job {}", job.id)).await?;
+                    }
+                    Err(e) => {
+                        error!("failed to enqueue code_execution job: {}", e);
+                        phase::transition(&app, &phase_pool, &phase_state, AgentPhase::Error, &e).await?;
+                    }
+                }
+            }
+
+            phase::transition(&app, &phase_pool, &phase_state, AgentPhase::Idle, "chat_with_agent: response delivered").await?;
             info!("Generated response for user message");
             Ok(response)
         }
         Err(e) => {
             error!("DeepSeek call failed: {}", e);
+            phase::transition(&app, &phase_pool, &phase_state, AgentPhase::Error, &e).await?;
+            phase::transition(&app, &phase_pool, &phase_state, AgentPhase::Idle, "chat_with_agent: recovered after error").await?;
             Err(e)
         }
     }
 }
 
 #[tauri::command]
-async fn get_agent_status(state: State<'_, AppState>) -> Result<AgentState, String> {
-    let agent_state = state.lock().await;
-    Ok(agent_state.clone())
+async fn get_agent_status(
+    state: State<'_, AppState>,
+    memory_pool: State<'_, memory::MemoryPool>,
+) -> Result<AgentState, String> {
+    let mut agent_state = state.lock().await.clone();
+    agent_state.memory_count = memory::count(&memory_pool).await.map_err(|e| e.to_string())? as usize;
+    Ok(agent_state)
 }
 
 #[tauri::command]
@@ -219,26 +287,13 @@ async fn write_file(path: String, contents: String) -> Result<(), String> {
 }
 
 #[tauri::command]
-async fn execute_code(language: String, code: String) -> Result<String, String> {
+async fn execute_code(
+    language: String,
+    code: String,
+    runner_config: State<'_, runner::RunnerConfig>,
+) -> Result<runner::ExecResult, String> {
     debug!("Executing {} code", language);
-    Err("Code execution not implemented in standalone mode".to_string())
-}
-
-#[tauri::command]
-async fn search_memories(query: String, state: State<'_, AppState>) -> Result<Vec<String>, String> {
-    debug!("Searching memories for: {}", query);
-
-    let agent_state = state.lock().await;
-
-    let results: Vec<String> = agent_state
-        .active_conversations
-        .iter()
-        .filter(|conv| conv.to_lowercase().contains(&query.to_lowercase()))
-        .cloned()
-        .collect();
-
-    info!("Found {} memory results for query: {}", results.len(), query);
-    Ok(results)
+    runner::run(&runner_config, &language, &code).await
 }
 
 #[tokio::main]
@@ -252,9 +307,30 @@ async fn main() {
     let config = Config::from_env();
     let app_state: AppState = Arc::new(AsyncMutex::new(AgentState::default()));
 
+    let data_dir = std::env::temp_dir().join("nova-agent").join("db");
+    std::fs::create_dir_all(&data_dir).expect("failed to create NOVA data directory");
+    let job_pool = jobs::connect(&data_dir.join("jobs.sqlite"))
+        .await
+        .expect("failed to initialize job queue");
+
+    let runner_config = runner::RunnerConfig::from_env();
+    jobs::spawn_worker(job_pool.clone(), runner_config.clone());
+
+    let memory_pool = memory::connect(&data_dir.join("memories.sqlite"))
+        .await
+        .expect("failed to initialize memory store");
+    let phase_pool = phase::connect(&data_dir.join("phase.sqlite"))
+        .await
+        .expect("failed to initialize agent phase store");
+
     tauri::Builder::default()
         .manage(config)
         .manage(app_state)
+        .manage(job_pool)
+        .manage(runner_config)
+        .manage(memory_pool)
+        .manage(phase_pool)
+        .manage(phase::PhaseState::default())
         .setup(|_app| {
             info!("NOVA Agent setup completed successfully");
             Ok(())
@@ -266,7 +342,10 @@ async fn main() {
             read_file,
             write_file,
             execute_code,
-            search_memories
+            memory::search_memories,
+            jobs::enqueue_job,
+            jobs::get_jobs,
+            phase::get_agent_phase
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
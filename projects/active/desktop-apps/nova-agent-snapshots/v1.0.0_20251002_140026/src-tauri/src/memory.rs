@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{FromRow, Pool, Row, Sqlite};
+use tauri::State;
+
+pub type MemoryPool = Pool<Sqlite>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MemoryEntry {
+    pub id: i64,
+    pub role: String,
+    pub content: String,
+    pub timestamp: String,
+    pub project: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemorySearchResult {
+    pub entry: MemoryEntry,
+    pub score: f64,
+    pub snippet: String,
+}
+
+pub async fn connect(db_path: &std::path::Path) -> Result<MemoryPool, sqlx::Error> {
+    let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+    let pool = SqlitePoolOptions::new().max_connections(5).connect(&db_url).await?;
+    init_memories(&pool).await?;
+    Ok(pool)
+}
+
+async fn init_memories(pool: &MemoryPool) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS memories (
+            rowid INTEGER PRIMARY KEY,
+            role TEXT NOT NULL,
+            content TEXT NOT NULL,
+            timestamp TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+            project TEXT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE VIRTUAL TABLE IF NOT EXISTS memories_fts USING fts5(content, content='memories', content_rowid='rowid')",
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TRIGGER IF NOT EXISTS memories_ai AFTER INSERT ON memories BEGIN
+            INSERT INTO memories_fts(rowid, content) VALUES (new.rowid, new.content);
+        END
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TRIGGER IF NOT EXISTS memories_ad AFTER DELETE ON memories BEGIN
+            INSERT INTO memories_fts(memories_fts, rowid, content) VALUES ('delete', old.rowid, old.content);
+        END
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TRIGGER IF NOT EXISTS memories_au AFTER UPDATE ON memories BEGIN
+            INSERT INTO memories_fts(memories_fts, rowid, content) VALUES ('delete', old.rowid, old.content);
+            INSERT INTO memories_fts(rowid, content) VALUES (new.rowid, new.content);
+        END
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn record_turn(
+    pool: &MemoryPool,
+    role: &str,
+    content: &str,
+    project: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO memories (role, content, project) VALUES (?1, ?2, ?3)")
+        .bind(role)
+        .bind(content)
+        .bind(project)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub async fn count(pool: &MemoryPool) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar("SELECT count(*) FROM memories").fetch_one(pool).await
+}
+
+/// FTS5 reserves `:`, `-`, `"`, and `(`/`)` as query syntax, so raw user
+/// input containing them (e.g. "9am-5pm", "don't forget") throws a SQL
+/// error instead of searching. Wrapping each token in double quotes makes
+/// it an FTS5 string literal, disabling that syntax; embedded `"` are
+/// escaped by doubling, per FTS5's own quoting rule. Tokens stay
+/// space-separated, which FTS5 ANDs together as before.
+fn sanitize_fts5_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .map(|token| format!("\"{}\"", token.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[tauri::command]
+pub async fn search_memories(
+    query: String,
+    pool: State<'_, MemoryPool>,
+) -> Result<Vec<MemorySearchResult>, String> {
+    tracing::debug!("Searching memories for: {}", query);
+
+    let fts_query = sanitize_fts5_query(&query);
+    if fts_query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rows = sqlx::query(
+        r#"
+        SELECT m.rowid AS id, m.role, m.content, m.timestamp, m.project,
+               bm25(memories_fts) AS score,
+               snippet(memories_fts, 0, '[', ']', '...', 8) AS snippet
+        FROM memories_fts
+        JOIN memories m ON m.rowid = memories_fts.rowid
+        WHERE memories_fts MATCH ?1
+        ORDER BY bm25(memories_fts)
+        LIMIT 25
+        "#,
+    )
+    .bind(&fts_query)
+    .fetch_all(pool.inner())
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let results = rows
+        .into_iter()
+        .map(|row| MemorySearchResult {
+            entry: MemoryEntry {
+                id: row.get("id"),
+                role: row.get("role"),
+                content: row.get("content"),
+                timestamp: row.get("timestamp"),
+                project: row.get("project"),
+            },
+            score: row.get("score"),
+            snippet: row.get("snippet"),
+        })
+        .collect();
+
+    Ok(results)
+}
@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{FromRow, Pool, Sqlite};
+use tauri::{AppHandle, Emitter, State};
+use tokio::sync::Mutex as AsyncMutex;
+use tracing::warn;
+
+pub type PhasePool = Pool<Sqlite>;
+
+pub async fn connect(db_path: &std::path::Path) -> Result<PhasePool, sqlx::Error> {
+    let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+    let pool = SqlitePoolOptions::new().max_connections(5).connect(&db_url).await?;
+    init_phase_table(&pool).await?;
+    Ok(pool)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentPhase {
+    Idle,
+    Thinking,
+    CallingTool,
+    Executing,
+    Error,
+}
+
+impl AgentPhase {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AgentPhase::Idle => "idle",
+            AgentPhase::Thinking => "thinking",
+            AgentPhase::CallingTool => "calling_tool",
+            AgentPhase::Executing => "executing",
+            AgentPhase::Error => "error",
+        }
+    }
+
+    /// The lifecycle is `Idle -> Thinking -> CallingTool -> Executing -> Idle`,
+    /// with `Error` reachable from anywhere and only able to return to `Idle`.
+    fn can_transition_to(&self, next: AgentPhase) -> bool {
+        use AgentPhase::*;
+        matches!(
+            (self, next),
+            (Idle, Thinking)
+                | (Thinking, CallingTool)
+                | (Thinking, Idle)
+                | (CallingTool, Executing)
+                | (Executing, Idle)
+                | (_, Error)
+                | (Error, Idle)
+        )
+    }
+}
+
+pub struct PhaseState {
+    current: AsyncMutex<AgentPhase>,
+}
+
+impl Default for PhaseState {
+    fn default() -> Self {
+        Self {
+            current: AsyncMutex::new(AgentPhase::Idle),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+struct TransitionRow {
+    from_phase: String,
+    to_phase: String,
+    reason: String,
+    at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct PhaseTransitionEvent {
+    from: String,
+    to: String,
+    reason: String,
+}
+
+pub async fn init_phase_table(pool: &PhasePool) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS state_transitions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            from_phase TEXT NOT NULL,
+            to_phase TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Attempts to move the agent to `next`, rejecting illegal jumps (e.g.
+/// `Idle -> Executing`). On success, records the transition and emits a
+/// `agent-phase-changed` event for live subscribers.
+pub async fn transition(
+    app: &AppHandle,
+    pool: &PhasePool,
+    state: &PhaseState,
+    next: AgentPhase,
+    reason: &str,
+) -> Result<(), String> {
+    let mut current = state.current.lock().await;
+
+    if !current.can_transition_to(next) {
+        return Err(format!(
+            "illegal agent phase transition: {:?} -> {:?}",
+            *current, next
+        ));
+    }
+
+    sqlx::query("INSERT INTO state_transitions (from_phase, to_phase, reason) VALUES (?1, ?2, ?3)")
+        .bind(current.as_str())
+        .bind(next.as_str())
+        .bind(reason)
+        .execute(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let event = PhaseTransitionEvent {
+        from: current.as_str().to_string(),
+        to: next.as_str().to_string(),
+        reason: reason.to_string(),
+    };
+    if let Err(e) = app.emit("agent-phase-changed", &event) {
+        warn!("failed to emit agent-phase-changed event: {}", e);
+    }
+
+    *current = next;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_agent_phase(state: State<'_, PhaseState>) -> Result<AgentPhase, String> {
+    Ok(*state.current.lock().await)
+}
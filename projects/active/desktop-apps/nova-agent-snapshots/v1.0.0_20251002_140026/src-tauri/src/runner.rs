@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncReadExt;
+use tokio::process::Command;
+use tracing::{debug, warn};
+
+const MAX_OUTPUT_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecResult {
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+    pub duration_ms: u64,
+    pub timed_out: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct LanguageSpec {
+    pub command: String,
+    pub args_template: Vec<String>,
+    pub file_extension: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RunnerConfig {
+    pub languages: HashMap<String, LanguageSpec>,
+    pub timeout: Duration,
+}
+
+impl RunnerConfig {
+    pub fn from_env() -> Self {
+        let mut languages = HashMap::new();
+
+        languages.insert(
+            "python".to_string(),
+            LanguageSpec {
+                command: env::var("NOVA_PYTHON_BIN").unwrap_or_else(|_| "python3".to_string()),
+                args_template: vec!["{file}".to_string()],
+                file_extension: "py".to_string(),
+            },
+        );
+        languages.insert(
+            "node".to_string(),
+            LanguageSpec {
+                command: env::var("NOVA_NODE_BIN").unwrap_or_else(|_| "node".to_string()),
+                args_template: vec!["{file}".to_string()],
+                file_extension: "js".to_string(),
+            },
+        );
+        languages.insert(
+            "bash".to_string(),
+            LanguageSpec {
+                command: env::var("NOVA_BASH_BIN").unwrap_or_else(|_| "bash".to_string()),
+                args_template: vec!["{file}".to_string()],
+                file_extension: "sh".to_string(),
+            },
+        );
+
+        let timeout_secs: u64 = env::var("NOVA_EXEC_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(10);
+
+        Self {
+            languages,
+            timeout: Duration::from_secs(timeout_secs),
+        }
+    }
+}
+
+/// Runs `code` in a fresh scratch directory through the interpreter registered
+/// for `language`, rejecting anything outside the configured allow-list.
+pub async fn run(config: &RunnerConfig, language: &str, code: &str) -> Result<ExecResult, String> {
+    let spec = config
+        .languages
+        .get(language)
+        .ok_or_else(|| format!("language '{}' is not in the execution allow-list", language))?;
+
+    let run_id = nanoid::nanoid!(12);
+    let scratch_dir = env::temp_dir().join("nova-agent").join("runs").join(&run_id);
+    tokio::fs::create_dir_all(&scratch_dir)
+        .await
+        .map_err(|e| format!("failed to create scratch directory: {}", e))?;
+
+    let source_path = scratch_dir.join(format!("main.{}", spec.file_extension));
+    tokio::fs::write(&source_path, code)
+        .await
+        .map_err(|e| format!("failed to write source file: {}", e))?;
+
+    let args: Vec<String> = spec
+        .args_template
+        .iter()
+        .map(|a| a.replace("{file}", &source_path.to_string_lossy()))
+        .collect();
+
+    let mut command = Command::new(&spec.command);
+    command
+        .args(&args)
+        .current_dir(&scratch_dir)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.as_std_mut().process_group(0);
+    }
+
+    let started = Instant::now();
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("failed to spawn {} interpreter: {}", language, e))?;
+
+    let mut stdout_pipe = child.stdout.take().expect("stdout piped");
+    let mut stderr_pipe = child.stderr.take().expect("stderr piped");
+
+    let stdout_task = tokio::spawn(async move { read_capped(&mut stdout_pipe).await });
+    let stderr_task = tokio::spawn(async move { read_capped(&mut stderr_pipe).await });
+
+    let wait_result = tokio::time::timeout(config.timeout, child.wait()).await;
+
+    let (timed_out, exit_code) = match wait_result {
+        Ok(Ok(status)) => (false, status.code()),
+        Ok(Err(e)) => {
+            warn!("failed to wait on {} process: {}", language, e);
+            (false, None)
+        }
+        Err(_) => {
+            kill_process_group(&mut child).await;
+            (true, None)
+        }
+    };
+
+    let stdout = stdout_task.await.unwrap_or_default();
+    let stderr = stderr_task.await.unwrap_or_default();
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    let _ = tokio::fs::remove_dir_all(&scratch_dir).await;
+    debug!(
+        "ran {} code in {}ms (timed_out={})",
+        language, duration_ms, timed_out
+    );
+
+    Ok(ExecResult {
+        exit_code,
+        stdout,
+        stderr,
+        duration_ms,
+        timed_out,
+    })
+}
+
+async fn read_capped<R: AsyncReadExt + Unpin>(pipe: &mut R) -> String {
+    let mut buf = Vec::with_capacity(4096);
+    let mut chunk = [0u8; 4096];
+    loop {
+        match pipe.read(&mut chunk).await {
+            Ok(0) => break,
+            Ok(n) => {
+                let remaining = MAX_OUTPUT_BYTES.saturating_sub(buf.len());
+                if remaining > 0 {
+                    buf.extend_from_slice(&chunk[..n.min(remaining)]);
+                }
+                // Keep draining past the cap instead of abandoning the pipe:
+                // otherwise a process that briefly exceeds MAX_OUTPUT_BYTES
+                // and then exits quickly would instead block on a full OS
+                // pipe buffer and get force-killed at the full timeout.
+            }
+            Err(_) => break,
+        }
+    }
+    String::from_utf8_lossy(&buf).into_owned()
+}
+
+async fn kill_process_group(child: &mut tokio::process::Child) {
+    #[cfg(unix)]
+    {
+        if let Some(pid) = child.id() {
+            unsafe {
+                libc::kill(-(pid as i32), libc::SIGKILL);
+            }
+        }
+    }
+    let _ = child.kill().await;
+}
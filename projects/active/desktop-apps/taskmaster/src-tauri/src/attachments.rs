@@ -0,0 +1,348 @@
+use crate::db::DbPool;
+use crate::models::Attachment;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use nanoid::nanoid;
+use sha2::{Digest, Sha256};
+use sqlx::Row;
+use tauri::{AppHandle, Manager, State};
+
+fn map_db_err(e: sqlx::Error) -> String {
+    match e {
+        sqlx::Error::RowNotFound => "Not found".to_string(),
+        other => format!("DB error: {}", other),
+    }
+}
+
+async fn get_setting_value(pool: &DbPool, key: &str) -> Result<Option<String>, String> {
+    sqlx::query("SELECT value FROM settings WHERE key = ?1")
+        .bind(key)
+        .fetch_optional(pool)
+        .await
+        .map_err(map_db_err)
+        .map(|row| row.map(|r| r.get::<String, _>("value")))
+}
+
+enum Backend {
+    Local { root: std::path::PathBuf },
+    S3 {
+        endpoint: String,
+        bucket: String,
+        region: String,
+        access_key: String,
+        secret_key: String,
+    },
+}
+
+async fn resolve_backend(app: &AppHandle, pool: &DbPool) -> Result<Backend, String> {
+    let kind = get_setting_value(pool, "attachments.backend")
+        .await?
+        .unwrap_or_else(|| "local".to_string());
+
+    match kind.as_str() {
+        "s3" => {
+            let get = |key: &str| get_setting_value(pool, key);
+            Ok(Backend::S3 {
+                endpoint: get("attachments.s3.endpoint")
+                    .await?
+                    .ok_or("attachments.s3.endpoint is not configured")?,
+                bucket: get("attachments.s3.bucket")
+                    .await?
+                    .ok_or("attachments.s3.bucket is not configured")?,
+                region: get("attachments.s3.region").await?.unwrap_or_else(|| "us-east-1".to_string()),
+                access_key: get("attachments.s3.access_key")
+                    .await?
+                    .ok_or("attachments.s3.access_key is not configured")?,
+                secret_key: get("attachments.s3.secret_key")
+                    .await?
+                    .ok_or("attachments.s3.secret_key is not configured")?,
+            })
+        }
+        _ => {
+            let app_dir = app
+                .path()
+                .app_data_dir()
+                .map_err(|e| format!("failed to resolve app data dir: {}", e))?;
+            Ok(Backend::Local {
+                root: app_dir.join("attachments"),
+            })
+        }
+    }
+}
+
+impl Backend {
+    async fn put(&self, storage_key: &str, bytes: &[u8], content_type: &str) -> Result<(), String> {
+        match self {
+            Backend::Local { root } => {
+                tokio::fs::create_dir_all(root)
+                    .await
+                    .map_err(|e| format!("failed to create attachment dir: {}", e))?;
+                tokio::fs::write(root.join(storage_key), bytes)
+                    .await
+                    .map_err(|e| format!("failed to write attachment: {}", e))
+            }
+            Backend::S3 { .. } => {
+                let url = self.sign_request(storage_key, "PUT", 60)?;
+                let client = reqwest::Client::new();
+                let response = client
+                    .put(url)
+                    .header("Content-Type", content_type)
+                    .body(bytes.to_vec())
+                    .send()
+                    .await
+                    .map_err(|e| format!("S3 upload failed: {}", e))?;
+                if !response.status().is_success() {
+                    return Err(format!("S3 upload returned status {}", response.status()));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn delete(&self, storage_key: &str) -> Result<(), String> {
+        match self {
+            Backend::Local { root } => {
+                let path = root.join(storage_key);
+                if path.exists() {
+                    tokio::fs::remove_file(path)
+                        .await
+                        .map_err(|e| format!("failed to delete attachment: {}", e))?;
+                }
+                Ok(())
+            }
+            Backend::S3 { .. } => {
+                let url = self.sign_request(storage_key, "DELETE", 60)?;
+                let client = reqwest::Client::new();
+                client
+                    .delete(url)
+                    .send()
+                    .await
+                    .map_err(|e| format!("S3 delete failed: {}", e))?;
+                Ok(())
+            }
+        }
+    }
+
+    fn url(&self, storage_key: &str, expires_secs: u64) -> Result<String, String> {
+        match self {
+            Backend::Local { root } => Ok(format!("file://{}", root.join(storage_key).display())),
+            Backend::S3 { .. } => self.sign_request(storage_key, "GET", expires_secs),
+        }
+    }
+
+    /// Builds a real SigV4 presigned URL (query-string signing, as used by
+    /// S3 and S3-compatible services like MinIO/R2) so this backend can
+    /// actually authenticate against one, rather than inventing its own
+    /// signing scheme.
+    fn sign_request(&self, storage_key: &str, method: &str, expires_secs: u64) -> Result<String, String> {
+        let Backend::S3 { endpoint, bucket, region, access_key, secret_key } = self else {
+            return Err("sign_request called on a non-S3 backend".to_string());
+        };
+
+        let scheme = if endpoint.starts_with("http://") { "http" } else { "https" };
+        let host = endpoint
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_end_matches('/')
+            .to_string();
+
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date_stamp = now.format("%Y%m%d").to_string();
+        let credential_scope = format!("{}/{}/s3/aws4_request", date_stamp, region);
+        let credential = format!("{}/{}", access_key, credential_scope);
+
+        let canonical_uri = format!(
+            "/{}/{}",
+            uri_encode(bucket),
+            storage_key.split('/').map(uri_encode).collect::<Vec<_>>().join("/")
+        );
+
+        let mut query_params = vec![
+            ("X-Amz-Algorithm".to_string(), "AWS4-HMAC-SHA256".to_string()),
+            ("X-Amz-Credential".to_string(), credential),
+            ("X-Amz-Date".to_string(), amz_date.clone()),
+            ("X-Amz-Expires".to_string(), expires_secs.to_string()),
+            ("X-Amz-SignedHeaders".to_string(), "host".to_string()),
+        ];
+        query_params.sort();
+        let canonical_querystring = query_params
+            .iter()
+            .map(|(k, v)| format!("{}={}", uri_encode(k), uri_encode(v)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let canonical_headers = format!("host:{}\n", host);
+        let signed_headers = "host";
+        let payload_hash = "UNSIGNED-PAYLOAD";
+
+        let canonical_request = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}",
+            method, canonical_uri, canonical_querystring, canonical_headers, signed_headers, payload_hash
+        );
+
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            credential_scope,
+            sha256_hex(&canonical_request)
+        );
+
+        let k_date = hmac_sha256(format!("AWS4{}", secret_key).as_bytes(), &date_stamp)?;
+        let k_region = hmac_sha256(&k_date, region)?;
+        let k_service = hmac_sha256(&k_region, "s3")?;
+        let k_signing = hmac_sha256(&k_service, "aws4_request")?;
+        let signature = hex::encode(hmac_sha256(&k_signing, &string_to_sign)?);
+
+        Ok(format!(
+            "{}://{}{}?{}&X-Amz-Signature={}",
+            scheme, host, canonical_uri, canonical_querystring, signature
+        ))
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Result<Vec<u8>, String> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).map_err(|e| format!("failed to construct HMAC: {}", e))?;
+    mac.update(data.as_bytes());
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+fn sha256_hex(data: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Percent-encodes a single path/query component per SigV4's URI-encode
+/// rules: RFC 3986 unreserved characters pass through unescaped, everything
+/// else (including `/`, encoded per-segment by the caller) is escaped.
+fn uri_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => out.push(b as char),
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+/// Strips anything that could turn a path component into a path-traversal
+/// or absolute-path escape (separators, `..`, drive letters) down to a safe
+/// alphanumeric/`-`/`_` slug, so caller-supplied `task_id`/`filename` values
+/// can never land outside the attachments root.
+fn sanitize_path_component(s: &str) -> String {
+    let cleaned: String = s
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+    if cleaned.is_empty() {
+        "_".to_string()
+    } else {
+        cleaned
+    }
+}
+
+#[tauri::command]
+pub async fn attach_file(
+    app: AppHandle,
+    pool: State<'_, DbPool>,
+    task_id: String,
+    filename: String,
+    content_type: String,
+    data: Vec<u8>,
+) -> Result<Attachment, String> {
+    let backend = resolve_backend(&app, pool.inner()).await?;
+
+    let id = nanoid!(12);
+    // The on-disk name is derived only from `id` (itself a safe nanoid) and a
+    // sanitized `task_id`; the caller-supplied `filename` is kept only as
+    // attachment metadata so it can't be used to escape the attachments root.
+    let storage_key = format!("{}/{}", sanitize_path_component(&task_id), id);
+    let size = data.len() as i64;
+
+    backend.put(&storage_key, &data, &content_type).await?;
+
+    let mut tx = pool.inner().begin().await.map_err(map_db_err)?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO attachments (id, task_id, filename, content_type, size, storage_key)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        "#,
+    )
+    .bind(&id)
+    .bind(&task_id)
+    .bind(&filename)
+    .bind(&content_type)
+    .bind(size)
+    .bind(&storage_key)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_db_err)?;
+
+    sqlx::query("UPDATE tasks SET attachments = attachments + 1 WHERE id = ?1")
+        .bind(&task_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err)?;
+
+    let attachment = sqlx::query_as::<_, Attachment>("SELECT * FROM attachments WHERE id = ?1")
+        .bind(&id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(map_db_err)?;
+
+    tx.commit().await.map_err(map_db_err)?;
+
+    Ok(attachment)
+}
+
+#[tauri::command]
+pub async fn list_attachments(pool: State<'_, DbPool>, task_id: String) -> Result<Vec<Attachment>, String> {
+    sqlx::query_as::<_, Attachment>("SELECT * FROM attachments WHERE task_id = ?1 ORDER BY created_at DESC")
+        .bind(task_id)
+        .fetch_all(pool.inner())
+        .await
+        .map_err(map_db_err)
+}
+
+#[tauri::command]
+pub async fn delete_attachment(app: AppHandle, pool: State<'_, DbPool>, id: String) -> Result<(), String> {
+    let attachment = sqlx::query_as::<_, Attachment>("SELECT * FROM attachments WHERE id = ?1")
+        .bind(&id)
+        .fetch_one(pool.inner())
+        .await
+        .map_err(map_db_err)?;
+
+    let backend = resolve_backend(&app, pool.inner()).await?;
+    backend.delete(&attachment.storage_key).await?;
+
+    let mut tx = pool.inner().begin().await.map_err(map_db_err)?;
+
+    sqlx::query("DELETE FROM attachments WHERE id = ?1")
+        .bind(&id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err)?;
+
+    sqlx::query("UPDATE tasks SET attachments = attachments - 1 WHERE id = ?1")
+        .bind(&attachment.task_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err)?;
+
+    tx.commit().await.map_err(map_db_err)
+}
+
+#[tauri::command]
+pub async fn get_attachment_url(app: AppHandle, pool: State<'_, DbPool>, id: String) -> Result<String, String> {
+    let attachment = sqlx::query_as::<_, Attachment>("SELECT * FROM attachments WHERE id = ?1")
+        .bind(&id)
+        .fetch_one(pool.inner())
+        .await
+        .map_err(map_db_err)?;
+
+    let backend = resolve_backend(&app, pool.inner()).await?;
+    backend.url(&attachment.storage_key, 300)
+}
@@ -1,6 +1,8 @@
+mod attachments;
 mod commands;
 mod db;
 mod models;
+mod notifier;
 
 use tauri::Manager;
 
@@ -10,11 +12,15 @@ pub fn run() {
         .plugin(tauri_plugin_log::Builder::new().build())
         .plugin(tauri_plugin_store::Builder::new().build())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_notification::init())
         .setup(|app| {
             let handle = app.handle().clone();
             let pool = tauri::async_runtime::block_on(async move {
                 db::init_db(&handle).await.expect("Failed to initialize database")
             });
+            tauri::async_runtime::block_on(notifier::init_notifications(&pool))
+                .expect("Failed to initialize notifications table");
+            notifier::spawn_scheduler(app.handle().clone(), pool.clone());
             app.handle().manage(pool);
             Ok(())
         })
@@ -29,6 +35,15 @@ pub fn run() {
             commands::get_sessions,
             commands::save_setting,
             commands::get_setting,
+            attachments::attach_file,
+            attachments::list_attachments,
+            attachments::delete_attachment,
+            attachments::get_attachment_url,
+            db::db_status,
+            db::db_reset,
+            db::db_vacuum,
+            notifier::get_pending_notifications,
+            notifier::snooze_notification,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,257 @@
+use crate::db::DbPool;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_notification::NotificationExt;
+use tracing::{error, warn};
+
+const TICK_INTERVAL: Duration = Duration::from_secs(60);
+const DUE_DATE_LEAD_MINUTES: i64 = 30;
+const OPEN_SESSION_THRESHOLD_HOURS: i64 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    TaskDueSoon,
+    SessionLeftOpen,
+}
+
+impl NotificationKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            NotificationKind::TaskDueSoon => "task_due_soon",
+            NotificationKind::SessionLeftOpen => "session_left_open",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PendingNotification {
+    pub id: String,
+    pub kind: String,
+    pub task_id: Option<String>,
+    pub session_id: Option<String>,
+    pub fire_at: String,
+    pub delivered_at: Option<String>,
+}
+
+pub async fn init_notifications(pool: &DbPool) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS notifications (
+            id TEXT PRIMARY KEY,
+            kind TEXT NOT NULL,
+            task_id TEXT,
+            session_id TEXT,
+            fire_at TEXT NOT NULL,
+            delivered_at TEXT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Scans tasks approaching their due date and sessions left open past the
+/// threshold, scheduling a not-yet-delivered reminder for each newly-seen
+/// one, then delivers every reminder whose `fire_at` has arrived.
+pub fn spawn_scheduler(app: AppHandle, pool: DbPool) {
+    tokio::spawn(async move {
+        let mut tick = tokio::time::interval(TICK_INTERVAL);
+        loop {
+            tick.tick().await;
+            if let Err(e) = scan_and_notify(&app, &pool).await {
+                error!("notification scan failed: {}", e);
+            }
+        }
+    });
+}
+
+async fn scan_and_notify(app: &AppHandle, pool: &DbPool) -> Result<(), sqlx::Error> {
+    schedule_due_tasks(pool).await?;
+    schedule_open_sessions(pool).await?;
+    deliver_due_notifications(app, pool).await?;
+    Ok(())
+}
+
+async fn schedule_due_tasks(pool: &DbPool) -> Result<(), sqlx::Error> {
+    let due_tasks: Vec<(String,)> = sqlx::query_as(
+        r#"
+        SELECT t.id
+        FROM tasks t
+        WHERE t.done = 0
+          AND t.due_date IS NOT NULL
+          AND (julianday(t.due_date) - julianday('now')) * 1440 <= ?1
+          AND (julianday(t.due_date) - julianday('now')) * 1440 >= 0
+          AND NOT EXISTS (
+              SELECT 1 FROM notifications n
+              WHERE n.kind = 'task_due_soon' AND n.task_id = t.id
+          )
+        "#,
+    )
+    .bind(DUE_DATE_LEAD_MINUTES)
+    .fetch_all(pool)
+    .await?;
+
+    for (task_id,) in due_tasks {
+        schedule(pool, NotificationKind::TaskDueSoon, Some(&task_id), None).await?;
+    }
+
+    Ok(())
+}
+
+async fn schedule_open_sessions(pool: &DbPool) -> Result<(), sqlx::Error> {
+    let open_sessions: Vec<(String,)> = sqlx::query_as(
+        r#"
+        SELECT s.id
+        FROM sessions s
+        WHERE s.end_at IS NULL
+          AND (julianday('now') - julianday(s.start_at)) * 24 >= ?1
+          AND NOT EXISTS (
+              SELECT 1 FROM notifications n
+              WHERE n.kind = 'session_left_open' AND n.session_id = s.id
+          )
+        "#,
+    )
+    .bind(OPEN_SESSION_THRESHOLD_HOURS)
+    .fetch_all(pool)
+    .await?;
+
+    for (session_id,) in open_sessions {
+        schedule(pool, NotificationKind::SessionLeftOpen, None, Some(&session_id)).await?;
+    }
+
+    Ok(())
+}
+
+/// Records a reminder as due-but-undelivered. `fire_at` is set to now, since
+/// the threshold queries above already confirmed the condition holds; actual
+/// delivery happens on the next `deliver_due_notifications` pass (normally
+/// the same tick), or later if a caller snoozes it first.
+async fn schedule(
+    pool: &DbPool,
+    kind: NotificationKind,
+    task_id: Option<&str>,
+    session_id: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    let id = nanoid::nanoid!(12);
+    let fire_at = chrono::Utc::now().to_rfc3339();
+
+    sqlx::query(
+        r#"
+        INSERT INTO notifications (id, kind, task_id, session_id, fire_at, delivered_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, NULL)
+        "#,
+    )
+    .bind(id)
+    .bind(kind.as_str())
+    .bind(task_id)
+    .bind(session_id)
+    .bind(fire_at)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Marks every scheduled reminder whose `fire_at` has arrived as delivered,
+/// then shows the OS notification and emits the `reminder` event for it.
+async fn deliver_due_notifications(app: &AppHandle, pool: &DbPool) -> Result<(), sqlx::Error> {
+    let due: Vec<PendingNotification> = sqlx::query_as(
+        "SELECT * FROM notifications WHERE delivered_at IS NULL AND datetime(fire_at) <= datetime('now') ORDER BY fire_at",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for notification in due {
+        let body = match notification_body(pool, &notification).await? {
+            Some(body) => body,
+            // The task/session this reminder was about no longer exists.
+            None => continue,
+        };
+        deliver(app, pool, notification, &body).await?;
+    }
+
+    Ok(())
+}
+
+async fn notification_body(pool: &DbPool, notification: &PendingNotification) -> Result<Option<String>, sqlx::Error> {
+    match notification.kind.as_str() {
+        "task_due_soon" => {
+            let Some(task_id) = &notification.task_id else { return Ok(None) };
+            let title: Option<(String,)> = sqlx::query_as("SELECT title FROM tasks WHERE id = ?1")
+                .bind(task_id)
+                .fetch_optional(pool)
+                .await?;
+            Ok(title.map(|(title,)| format!("\"{}\" is due soon", title)))
+        }
+        "session_left_open" => Ok(Some(
+            "A session has been running for a while — remember to end it".to_string(),
+        )),
+        _ => Ok(None),
+    }
+}
+
+async fn deliver(
+    app: &AppHandle,
+    pool: &DbPool,
+    notification: PendingNotification,
+    body: &str,
+) -> Result<(), sqlx::Error> {
+    let delivered_at = chrono::Utc::now().to_rfc3339();
+
+    sqlx::query("UPDATE notifications SET delivered_at = ?1 WHERE id = ?2")
+        .bind(&delivered_at)
+        .bind(&notification.id)
+        .execute(pool)
+        .await?;
+
+    if let Err(e) = app.notification().builder().title("VibePilot").body(body).show() {
+        warn!("failed to show OS notification: {}", e);
+    }
+
+    if let Err(e) = app.emit(
+        "reminder",
+        &PendingNotification {
+            delivered_at: Some(delivered_at),
+            ..notification
+        },
+    ) {
+        warn!("failed to emit reminder event: {}", e);
+    }
+
+    Ok(())
+}
+
+/// Reminders scheduled but not yet shown to the user.
+#[tauri::command]
+pub async fn get_pending_notifications(pool: State<'_, DbPool>) -> Result<Vec<PendingNotification>, String> {
+    sqlx::query_as::<_, PendingNotification>(
+        "SELECT * FROM notifications WHERE delivered_at IS NULL ORDER BY fire_at DESC LIMIT 50",
+    )
+    .fetch_all(pool.inner())
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Pushes a notification's `fire_at` forward and re-arms it for delivery,
+/// so `deliver_due_notifications` leaves it alone until the new time instead
+/// of it staying permanently delivered.
+#[tauri::command]
+pub async fn snooze_notification(
+    pool: State<'_, DbPool>,
+    id: String,
+    snooze_minutes: i64,
+) -> Result<(), String> {
+    sqlx::query(
+        "UPDATE notifications SET fire_at = datetime('now', '+' || ?1 || ' minutes'), delivered_at = NULL WHERE id = ?2",
+    )
+    .bind(snooze_minutes)
+    .bind(id)
+    .execute(pool.inner())
+    .await
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}